@@ -56,19 +56,10 @@ poll_compute_json() {
 }
 
 wait_compute_healthy() {
-  local dep="$1"
-  local i
-  for i in $(seq 1 120); do
-    if out="$(run_cli compute status -x "$dep" --json 2>/dev/null)"; then
-      st="$(echo "$out" | jq -r '.status // empty')"
-      if [[ "$st" == "Healthy" ]]; then
-        return 0
-      fi
-    fi
-    sleep 5
-  done
-  echo "compute did not become Healthy in time" >&2
-  return 1
+  run_cli wait compute "$1" --timeout 10m --probe tcp >/dev/null || {
+    echo "compute did not become running in time" >&2
+    return 1
+  }
 }
 
 wait_compute_row() {
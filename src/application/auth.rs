@@ -6,3 +6,22 @@ use crate::domain::errors::config_error::ConfigError;
 pub fn get_auth_token() -> Result<String, ConfigError> {
     config::load_jwt_token()
 }
+
+/// Decode the claims of a JWT without verifying its signature (display and
+/// diagnostics only; the API does the verification).
+pub fn jwt_claims(token: &str) -> Result<serde_json::Value, ConfigError> {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return Err(ConfigError::IoError("Invalid JWT token format".to_string()));
+    }
+
+    // JWT segments are base64url without padding
+    let decoded = base64::Engine::decode(
+        &base64::engine::general_purpose::URL_SAFE_NO_PAD,
+        parts[1].trim_end_matches('='),
+    )
+    .map_err(|e| ConfigError::IoError(format!("Failed to decode JWT payload: {}", e)))?;
+
+    serde_json::from_slice(&decoded)
+        .map_err(|e| ConfigError::IoError(format!("Invalid JSON in JWT payload: {}", e)))
+}
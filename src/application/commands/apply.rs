@@ -0,0 +1,925 @@
+use crate::application::commands::checkout::attached_branch_id;
+use crate::application::commands::compute::RESIZE_WAIT_TIMEOUT_SECS;
+use crate::application::dto::branch::{BranchRequest, ListBranchesResponse};
+use crate::application::dto::deploy::{CreateDeploymentRequest, ListDeploymentsResponse};
+use crate::application::dto::manifest::{
+    Manifest, ManifestBranch, ManifestDeployment, ManifestTenet,
+};
+use crate::application::dto::tenet::TenetDeployRequest;
+use crate::application::output::{print_json, print_table_or_json, OutputFormat};
+use crate::application::services::performance::{self, select_profile_id, PerformanceProfile};
+use crate::application::services::usage::{self, QuotaResource};
+use crate::application::services::{branch, compute, deploy, tenet};
+use crate::config::config::{
+    load_tenet_job_ids, save_tenet_endpoint, save_tenet_job_id, Config, TenetEndpoint,
+};
+use crate::domain::errors::manifest_error::ManifestError;
+use crate::domain::errors::tenet_error::TenetError;
+use crate::structure::{ApplyArgs, PlanArgs};
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+use tabled::Tabled;
+
+const DEFAULT_PERFORMANCE_PROFILE: &str = "gp.g1.xsmall";
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum ChangeAction {
+    Create,
+    Update,
+    Conflict,
+    Noop,
+}
+
+impl ChangeAction {
+    fn symbol(&self) -> &'static str {
+        match self {
+            ChangeAction::Create => "+ create",
+            ChangeAction::Update => "~ update",
+            ChangeAction::Conflict => "! conflict",
+            ChangeAction::Noop => "= no change",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct FieldDiff {
+    field: String,
+    current: String,
+    desired: String,
+}
+
+/// Desired resource carried along with a planned change so apply can execute it.
+#[derive(Debug)]
+enum ChangePayload {
+    Deployment(ManifestDeployment),
+    Branch(ManifestBranch),
+    Tenet {
+        tenet: ManifestTenet,
+        /// The server's job ID, which differs from the manifest's once created
+        job_id: String,
+        config_yaml: String,
+    },
+}
+
+#[derive(Serialize, Debug)]
+struct PlannedChange {
+    resource: &'static str,
+    deployment: String,
+    name: String,
+    action: ChangeAction,
+    deployment_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    diffs: Vec<FieldDiff>,
+    #[serde(skip)]
+    payload: ChangePayload,
+}
+
+#[derive(Serialize, Debug, Default)]
+struct PlanSummary {
+    create: usize,
+    update: usize,
+    conflict: usize,
+    noop: usize,
+}
+
+#[derive(Serialize, Debug)]
+struct Plan {
+    manifest: String,
+    changes: Vec<PlannedChange>,
+    summary: PlanSummary,
+}
+
+impl Plan {
+    fn new(manifest: String, changes: Vec<PlannedChange>) -> Self {
+        let mut summary = PlanSummary::default();
+        for change in &changes {
+            match change.action {
+                ChangeAction::Create => summary.create += 1,
+                ChangeAction::Update => summary.update += 1,
+                ChangeAction::Conflict => summary.conflict += 1,
+                ChangeAction::Noop => summary.noop += 1,
+            }
+        }
+        Plan {
+            manifest,
+            changes,
+            summary,
+        }
+    }
+
+    fn has_changes(&self) -> bool {
+        self.summary.create + self.summary.update > 0
+    }
+}
+
+#[derive(Tabled, Serialize)]
+struct PlanRow {
+    #[tabled(rename = "Action")]
+    action: String,
+    #[tabled(rename = "Resource")]
+    resource: String,
+    #[tabled(rename = "Deployment")]
+    deployment: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Details")]
+    details: String,
+}
+
+#[derive(Serialize, Debug)]
+struct ApplyResult {
+    resource: &'static str,
+    deployment: String,
+    name: String,
+    action: ChangeAction,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+pub async fn plan(args: &PlanArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let plan = build_plan(&args.file, config).await?;
+
+    if output_format == OutputFormat::Json {
+        print_json(&plan);
+        return Ok(());
+    }
+
+    display_plan(&plan);
+    if plan.summary.conflict > 0 {
+        println!(
+            "{} Conflicting fields cannot be changed in place; update the manifest or recreate the deployment.",
+            "⚠️".yellow()
+        );
+    } else if plan.has_changes() {
+        println!(
+            "{} Run 'guepard apply -f {}' to converge.",
+            "💡".yellow(),
+            args.file.display()
+        );
+    }
+    Ok(())
+}
+
+pub async fn apply(args: &ApplyArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let plan = build_plan(&args.file, config).await?;
+
+    if plan.summary.conflict > 0 {
+        if output_format == OutputFormat::Json {
+            print_json(&serde_json::json!({ "plan": plan, "results": [] }));
+        } else {
+            display_plan(&plan);
+        }
+        let names: Vec<String> = plan
+            .changes
+            .iter()
+            .filter(|c| c.action == ChangeAction::Conflict)
+            .map(|c| format!("{} '{}'", c.resource, c.name))
+            .collect();
+        return Err(ManifestError::Conflict(names.join(", ")).into());
+    }
+
+    if !plan.has_changes() {
+        if output_format == OutputFormat::Json {
+            print_json(&serde_json::json!({ "plan": plan, "results": [] }));
+        } else {
+            println!(
+                "{} Infrastructure matches {}. Nothing to do.",
+                "✅".green(),
+                plan.manifest
+            );
+        }
+        return Ok(());
+    }
+
+    check_required_env(&plan)?;
+
+    if output_format == OutputFormat::Table {
+        display_plan(&plan);
+    }
+
+    if !args.yes {
+        if output_format == OutputFormat::Json {
+            return Err(ManifestError::ApplyFailed(
+                "use --yes to apply with --json output".to_string(),
+            )
+            .into());
+        }
+        print!("{} Apply these changes? (y/N): ", "⚠️".yellow());
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if !input.trim().to_lowercase().starts_with('y') {
+            println!("{} Apply cancelled.", "ℹ️".blue());
+            return Ok(());
+        }
+        println!();
+    }
+
+    let mut results = Vec::new();
+    let mut created_deployments: HashMap<String, String> = HashMap::new();
+    let mut failure = None;
+
+    for change in plan
+        .changes
+        .iter()
+        .filter(|c| matches!(c.action, ChangeAction::Create | ChangeAction::Update))
+    {
+        let outcome = apply_change(change, &created_deployments, config).await;
+        match outcome {
+            Ok(id) => {
+                if output_format == OutputFormat::Table {
+                    println!(
+                        "{} {} {} '{}' {}",
+                        "✅".green(),
+                        if change.action == ChangeAction::Create {
+                            "Created"
+                        } else {
+                            "Updated"
+                        },
+                        change.resource,
+                        change.name,
+                        id.as_deref()
+                            .map(|i| format!("({})", i))
+                            .unwrap_or_default()
+                    );
+                }
+                if change.resource == "deployment" {
+                    if let Some(id) = &id {
+                        created_deployments.insert(change.deployment.clone(), id.clone());
+                    }
+                }
+                results.push(ApplyResult {
+                    resource: change.resource,
+                    deployment: change.deployment.clone(),
+                    name: change.name.clone(),
+                    action: change.action,
+                    status: "applied",
+                    id,
+                    error: None,
+                });
+            }
+            Err(e) => {
+                if output_format == OutputFormat::Table {
+                    println!(
+                        "{} Failed to {} {} '{}': {}",
+                        "❌".red(),
+                        if change.action == ChangeAction::Create {
+                            "create"
+                        } else {
+                            "update"
+                        },
+                        change.resource,
+                        change.name,
+                        e
+                    );
+                }
+                results.push(ApplyResult {
+                    resource: change.resource,
+                    deployment: change.deployment.clone(),
+                    name: change.name.clone(),
+                    action: change.action,
+                    status: "failed",
+                    id: None,
+                    error: Some(e.to_string()),
+                });
+                failure = Some(format!("{} '{}': {}", change.resource, change.name, e));
+                // Later changes may depend on this one, so stop here.
+                break;
+            }
+        }
+    }
+
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({ "plan": plan, "results": results }));
+    }
+
+    match failure {
+        Some(message) => Err(ManifestError::ApplyFailed(message).into()),
+        None => {
+            if output_format == OutputFormat::Table {
+                println!();
+                println!(
+                    "{} Applied {} change(s) from {}",
+                    "✅".green(),
+                    results.len(),
+                    plan.manifest
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn build_plan(path: &Path, config: &Config) -> Result<Plan> {
+    let manifest = Manifest::load(path)?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    let existing = deploy::list_deployments(config).await?;
+    let profiles = if manifest
+        .deployments
+        .iter()
+        .any(|d| d.performance_profile.is_some())
+    {
+        performance::list_performance_profiles(config).await?
+    } else {
+        Vec::new()
+    };
+    // An unknown label would otherwise only surface halfway through apply
+    for desired in &manifest.deployments {
+        if let Some(label) = &desired.performance_profile {
+            if select_profile_id(&profiles, label, &desired.provider, &desired.version).is_none() {
+                return Err(ManifestError::Invalid(format!(
+                    "deployment '{}': performance_profile '{}' does not exist for {} {}",
+                    desired.name, label, desired.provider, desired.version
+                ))
+                .into());
+            }
+        }
+    }
+
+    let tenet_job_ids = load_tenet_job_ids()?;
+
+    let mut changes = Vec::new();
+    for desired in &manifest.deployments {
+        let current = existing.iter().find(|d| d.repository_name == desired.name);
+
+        let (action, deployment_id, diffs) = match current {
+            None => (ChangeAction::Create, None, Vec::new()),
+            Some(current) => {
+                // Immutable fields cannot be changed in place; the performance
+                // profile can be resized.
+                let mut diffs = diff_deployment(desired, current);
+                let action = if !diffs.is_empty() {
+                    ChangeAction::Conflict
+                } else if let Some(diff) =
+                    diff_profile(desired, &current.id, &profiles, config).await?
+                {
+                    diffs.push(diff);
+                    ChangeAction::Update
+                } else {
+                    ChangeAction::Noop
+                };
+                (action, Some(current.id.clone()), diffs)
+            }
+        };
+
+        changes.push(PlannedChange {
+            resource: "deployment",
+            deployment: desired.name.clone(),
+            name: desired.name.clone(),
+            action,
+            deployment_id: deployment_id.clone(),
+            diffs,
+            payload: ChangePayload::Deployment(desired.clone()),
+        });
+
+        let branches = match &deployment_id {
+            Some(id) => branch::list_branches(id, config).await?,
+            None => Vec::new(),
+        };
+        for desired_branch in &desired.branches {
+            let action = if find_branch(&branches, &desired_branch.name).is_some() {
+                ChangeAction::Noop
+            } else {
+                ChangeAction::Create
+            };
+            changes.push(PlannedChange {
+                resource: "branch",
+                deployment: desired.name.clone(),
+                name: desired_branch.name.clone(),
+                action,
+                deployment_id: deployment_id.clone(),
+                diffs: Vec::new(),
+                payload: ChangePayload::Branch(desired_branch.clone()),
+            });
+        }
+
+        for desired_tenet in &desired.tenets {
+            let proxy_path = base_dir.join(&desired_tenet.proxy_config);
+            let config_yaml = fs::read_to_string(&proxy_path).map_err(|e| {
+                ManifestError::IoError(format!(
+                    "Cannot read proxy_config {}: {}",
+                    proxy_path.display(),
+                    e
+                ))
+            })?;
+
+            let job_id = resolve_tenet_job_id(&tenet_job_ids, &desired.name, &desired_tenet.job_id);
+            let (action, diffs) = tenet_change(
+                tenet::get_proxy_yaml(&job_id, config).await,
+                desired_tenet,
+                &config_yaml,
+            )?;
+
+            changes.push(PlannedChange {
+                resource: "tenet",
+                deployment: desired.name.clone(),
+                name: desired_tenet.job_id.clone(),
+                action,
+                deployment_id: deployment_id.clone(),
+                diffs,
+                payload: ChangePayload::Tenet {
+                    tenet: desired_tenet.clone(),
+                    job_id,
+                    config_yaml,
+                },
+            });
+        }
+    }
+
+    Ok(Plan::new(path.display().to_string(), changes))
+}
+
+/// Key under which apply records the server's job ID for a manifest Tenet.
+fn tenet_job_key(deployment: &str, job_id: &str) -> String {
+    format!("{}/{}", deployment, job_id)
+}
+
+/// The job ID to look a manifest Tenet up by: the one the server assigned when
+/// apply created it, else the manifest's own.
+fn resolve_tenet_job_id(
+    job_ids: &BTreeMap<String, String>,
+    deployment: &str,
+    job_id: &str,
+) -> String {
+    job_ids
+        .get(&tenet_job_key(deployment, job_id))
+        .cloned()
+        .unwrap_or_else(|| job_id.to_string())
+}
+
+/// Compare the remote proxy.yaml (or its absence) with the manifest's.
+fn tenet_change(
+    remote: Result<String, TenetError>,
+    desired: &ManifestTenet,
+    config_yaml: &str,
+) -> Result<(ChangeAction, Vec<FieldDiff>), TenetError> {
+    match remote {
+        Ok(remote) if same_yaml(&remote, config_yaml) => Ok((ChangeAction::Noop, Vec::new())),
+        Ok(_) => Ok((
+            ChangeAction::Update,
+            vec![FieldDiff {
+                field: "proxy_config".to_string(),
+                current: "remote proxy.yaml".to_string(),
+                desired: desired.proxy_config.clone(),
+            }],
+        )),
+        Err(TenetError::NotFound(_)) => Ok((ChangeAction::Create, Vec::new())),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fields that cannot be changed on an existing deployment.
+fn diff_deployment(
+    desired: &ManifestDeployment,
+    current: &ListDeploymentsResponse,
+) -> Vec<FieldDiff> {
+    let fields = [
+        ("provider", &current.database_provider, &desired.provider),
+        ("version", &current.database_version, &desired.version),
+        ("region", &current.region, &desired.region),
+        ("datacenter", &current.datacenter, &desired.datacenter),
+        ("type", &current.deployment_type, &desired.deployment_type),
+    ];
+
+    fields
+        .iter()
+        .filter(|(_, current, desired)| !current.eq_ignore_ascii_case(desired))
+        .map(|(field, current, desired)| FieldDiff {
+            field: field.to_string(),
+            current: current.to_string(),
+            desired: desired.to_string(),
+        })
+        .collect()
+}
+
+async fn diff_profile(
+    desired: &ManifestDeployment,
+    deployment_id: &str,
+    profiles: &[PerformanceProfile],
+    config: &Config,
+) -> Result<Option<FieldDiff>> {
+    let Some(label) = desired.performance_profile.as_deref() else {
+        return Ok(None);
+    };
+    let current_id = compute::list_compute(deployment_id, config)
+        .await?
+        .performance_profile_id;
+    // Labels were checked when the plan started
+    let desired_id = select_profile_id(profiles, label, &desired.provider, &desired.version)
+        .ok_or_else(|| {
+            ManifestError::Invalid(format!("performance_profile '{}' does not exist", label))
+        })?;
+    if current_id == desired_id {
+        return Ok(None);
+    }
+
+    let current_label = profiles
+        .iter()
+        .find(|p| p.id == current_id)
+        .map(|p| p.label_name.clone())
+        .unwrap_or(current_id);
+    Ok(Some(FieldDiff {
+        field: "performance_profile".to_string(),
+        current: current_label,
+        desired: label.to_string(),
+    }))
+}
+
+fn find_branch<'a>(
+    branches: &'a [ListBranchesResponse],
+    name: &str,
+) -> Option<&'a ListBranchesResponse> {
+    branches
+        .iter()
+        .find(|b| b.branch_name.as_deref() == Some(name) || b.label_name.as_deref() == Some(name))
+}
+
+fn same_yaml(a: &str, b: &str) -> bool {
+    match (
+        serde_yaml::from_str::<serde_yaml::Value>(a),
+        serde_yaml::from_str::<serde_yaml::Value>(b),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.trim() == b.trim(),
+    }
+}
+
+/// Fail before touching anything if a secret needed for creation is missing.
+fn check_required_env(plan: &Plan) -> Result<(), ManifestError> {
+    let mut missing = Vec::new();
+    for change in plan
+        .changes
+        .iter()
+        .filter(|c| c.action == ChangeAction::Create)
+    {
+        let var = match &change.payload {
+            ChangePayload::Deployment(d) => d.password_env.as_deref(),
+            ChangePayload::Tenet { tenet, .. } => Some(tenet.masking_salt_env.as_str()),
+            ChangePayload::Branch(_) => None,
+        };
+        match var {
+            Some(var) if env::var(var).is_err() => missing.push(var.to_string()),
+            None if change.resource == "deployment" => missing.push(format!(
+                "password_env for deployment '{}'",
+                change.deployment
+            )),
+            _ => {}
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(ManifestError::Invalid(format!(
+            "missing environment variables: {}",
+            missing.join(", ")
+        )))
+    }
+}
+
+async fn apply_change(
+    change: &PlannedChange,
+    created_deployments: &HashMap<String, String>,
+    config: &Config,
+) -> Result<Option<String>> {
+    let deployment_id = || {
+        change
+            .deployment_id
+            .clone()
+            .or_else(|| created_deployments.get(&change.deployment).cloned())
+            .ok_or_else(|| {
+                ManifestError::ApplyFailed(format!(
+                    "deployment '{}' does not exist",
+                    change.deployment
+                ))
+            })
+    };
+
+    match &change.payload {
+        ChangePayload::Deployment(desired) => {
+            if change.action == ChangeAction::Update {
+                let deployment_id = deployment_id()?;
+                let label = desired.performance_profile.as_deref().unwrap_or_default();
+                let profiles = performance::list_performance_profiles(config).await?;
+                let profile = performance::find_profile_for_deployment(
+                    &profiles,
+                    label,
+                    &desired.provider,
+                    &desired.version,
+                )?;
+                performance::apply_performance_profile(&deployment_id, &profile.id, config).await?;
+                compute::wait_until_healthy(
+                    &deployment_id,
+                    config,
+                    Duration::from_secs(RESIZE_WAIT_TIMEOUT_SECS),
+                )
+                .await?;
+                return Ok(Some(deployment_id));
+            }
+
+            let label = desired
+                .performance_profile
+                .as_deref()
+                .unwrap_or(DEFAULT_PERFORMANCE_PROFILE);
+            let performance_profile_id = performance::get_performance_profile_by_label(
+                label,
+                &desired.provider,
+                &desired.version,
+                config,
+            )
+            .await?;
+            let password_env = desired.password_env.as_deref().unwrap_or_default();
+            let database_password = env::var(password_env).map_err(|_| {
+                ManifestError::Invalid(format!("environment variable {} is not set", password_env))
+            })?;
+
+            let request = CreateDeploymentRequest {
+                repository_name: desired.name.clone(),
+                database_provider: desired.provider.clone(),
+                database_version: desired.version.clone(),
+                deployment_type: desired.deployment_type.clone(),
+                region: desired.region.clone(),
+                datacenter: desired.datacenter.clone(),
+                database_username: desired
+                    .username
+                    .clone()
+                    .unwrap_or_else(|| "guepard".to_string()),
+                database_password,
+                performance_profile_id,
+                node_id: desired.node_id.clone(),
+            };
+            usage::ensure_quota(QuotaResource::Deployment, config).await?;
+            let created = deploy::create_deployment(request, config).await?;
+            Ok(Some(created.id))
+        }
+        ChangePayload::Branch(desired) => {
+            let deployment_id = deployment_id()?;
+            let source_branch = attached_branch_id(&deployment_id, config).await?;
+            let snapshot_id = match &desired.snapshot {
+                Some(snapshot) => snapshot.clone(),
+                None => branch::list_branches(&deployment_id, config)
+                    .await?
+                    .into_iter()
+                    .find(|b| b.id == source_branch)
+                    .map(|b| b.snapshot_id)
+                    .ok_or_else(|| {
+                        ManifestError::ApplyFailed(format!(
+                            "no snapshot to branch '{}' from yet; re-run apply once the deployment is ready",
+                            desired.name
+                        ))
+                    })?,
+            };
+
+            let request = BranchRequest {
+                branch_name: Some(desired.name.clone()),
+                discard_changes: Some("false".to_string()),
+                checkout: false,
+                ephemeral: desired.ephemeral,
+            };
+            let created = branch::create_branch(
+                &deployment_id,
+                &source_branch,
+                &snapshot_id,
+                request,
+                config,
+            )
+            .await?;
+            Ok(Some(created.id))
+        }
+        ChangePayload::Tenet {
+            tenet: desired,
+            job_id,
+            config_yaml,
+        } => {
+            if change.action == ChangeAction::Update {
+                tenet::set_proxy_yaml(job_id, config_yaml, true, config).await?;
+                return Ok(Some(job_id.clone()));
+            }
+
+            let deployment_id = deployment_id()?;
+            let (upstream_host, upstream_port) =
+                match (&desired.upstream_host, desired.upstream_port) {
+                    (Some(host), Some(port)) => (host.clone(), port),
+                    (host, port) => {
+                        let compute = compute::list_compute(&deployment_id, config).await?;
+                        (
+                            host.clone().unwrap_or(compute.fqdn),
+                            port.unwrap_or(compute.port as u16),
+                        )
+                    }
+                };
+            let masking_salt = env::var(&desired.masking_salt_env).map_err(|_| {
+                ManifestError::Invalid(format!(
+                    "environment variable {} is not set",
+                    desired.masking_salt_env
+                ))
+            })?;
+
+            let request = TenetDeployRequest {
+                tenant_id: desired.tenant_id.clone(),
+                compute_job_id: Some(format!("{}-compute", desired.tenant_id)),
+                upstream_host,
+                upstream_port,
+                masking_salt,
+                config_dir: None,
+                config_yaml: config_yaml.clone(),
+                proxy_port: desired.proxy_port,
+                api_port: desired.api_port,
+            };
+            let response = tenet::deploy_tenet(&request, config).await?;
+            // Remembered for `guepard connect --via-tenet`
+            let _ = save_tenet_endpoint(
+                &response.job_id,
+                TenetEndpoint {
+                    host: response.host.clone(),
+                    proxy_port: response.proxy_port,
+                },
+            );
+            // The server picks the job ID; the next plan looks the Tenet up by it
+            if let Err(e) = save_tenet_job_id(
+                &tenet_job_key(&change.deployment, &desired.job_id),
+                &response.job_id,
+            ) {
+                eprintln!(
+                    "{} Could not record Tenet job ID '{}' ({}); the next apply will create '{}' again",
+                    "⚠️".yellow(),
+                    response.job_id,
+                    e,
+                    desired.job_id
+                );
+            }
+            Ok(Some(response.job_id))
+        }
+    }
+}
+
+fn display_plan(plan: &Plan) {
+    println!("{} Plan for {}", "📋".blue(), plan.manifest.cyan());
+    let rows: Vec<PlanRow> = plan
+        .changes
+        .iter()
+        .map(|c| PlanRow {
+            action: c.action.symbol().to_string(),
+            resource: c.resource.to_string(),
+            deployment: c.deployment.clone(),
+            name: c.name.clone(),
+            details: c
+                .diffs
+                .iter()
+                .map(|d| format!("{}: {} → {}", d.field, d.current, d.desired))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+        .collect();
+    print_table_or_json(rows, OutputFormat::Table);
+    println!(
+        "{} {} to create, {} to update, {} conflicting, {} unchanged",
+        "ℹ️".blue(),
+        plan.summary.create,
+        plan.summary.update,
+        plan.summary.conflict,
+        plan.summary.noop
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn desired() -> ManifestDeployment {
+        ManifestDeployment {
+            name: "staging-api".to_string(),
+            provider: "PostgreSQL".to_string(),
+            version: "16".to_string(),
+            region: "us-west-aws".to_string(),
+            datacenter: "us-west-aws".to_string(),
+            deployment_type: "REPOSITORY".to_string(),
+            performance_profile: None,
+            username: None,
+            password_env: Some("PW".to_string()),
+            node_id: None,
+            branches: Vec::new(),
+            tenets: Vec::new(),
+        }
+    }
+
+    fn existing(version: &str, region: &str) -> ListDeploymentsResponse {
+        ListDeploymentsResponse {
+            id: "dep-1".to_string(),
+            name: "staging-api".to_string(),
+            repository_name: "staging-api".to_string(),
+            status: "CREATED".to_string(),
+            deployment_type: "REPOSITORY".to_string(),
+            database_provider: "postgresql".to_string(),
+            database_version: version.to_string(),
+            fqdn: "x.guepard.run".to_string(),
+            port: Some(5432),
+            connection_string: None,
+            region: region.to_string(),
+            datacenter: "us-west-aws".to_string(),
+            created_date: "2025-06-01T00:00:00Z".to_string(),
+            created_by: "me".to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_deployment_is_empty_when_matching() {
+        assert!(diff_deployment(&desired(), &existing("16", "us-west-aws")).is_empty());
+    }
+
+    #[test]
+    fn diff_deployment_reports_changed_fields() {
+        let diffs = diff_deployment(&desired(), &existing("15", "eu-west-aws"));
+        let fields: Vec<&str> = diffs.iter().map(|d| d.field.as_str()).collect();
+        assert_eq!(fields, vec!["version", "region"]);
+        assert_eq!(diffs[0].current, "15");
+        assert_eq!(diffs[0].desired, "16");
+    }
+
+    #[test]
+    fn same_yaml_ignores_formatting() {
+        assert!(same_yaml("rules:\n  - a: 1\n", "rules: [{a: 1}]"));
+        assert!(!same_yaml("rules: []", "rules: [{a: 1}]"));
+    }
+
+    #[test]
+    fn plan_after_apply_finds_created_tenet() {
+        let tenet = ManifestTenet {
+            job_id: "staging-tenet".to_string(),
+            tenant_id: "staging".to_string(),
+            upstream_host: None,
+            upstream_port: None,
+            masking_salt_env: "TENET_SALT".to_string(),
+            proxy_config: "./proxy.yaml".to_string(),
+            proxy_port: None,
+            api_port: None,
+        };
+        let yaml = "rules: [{a: 1}]";
+        // The server only knows the job ID it assigned on create
+        let remote = |job_id: &str| match job_id {
+            "tenet-7f3a" => Ok(yaml.to_string()),
+            other => Err(TenetError::NotFound(other.to_string())),
+        };
+        let mut job_ids = BTreeMap::new();
+
+        let job_id = resolve_tenet_job_id(&job_ids, "staging-api", &tenet.job_id);
+        let (action, _) = tenet_change(remote(&job_id), &tenet, yaml).unwrap();
+        assert_eq!(action, ChangeAction::Create);
+
+        // What apply records after the create
+        job_ids.insert(
+            tenet_job_key("staging-api", &tenet.job_id),
+            "tenet-7f3a".to_string(),
+        );
+
+        let job_id = resolve_tenet_job_id(&job_ids, "staging-api", &tenet.job_id);
+        assert_eq!(job_id, "tenet-7f3a");
+        let (action, diffs) = tenet_change(remote(&job_id), &tenet, yaml).unwrap();
+        assert_eq!(action, ChangeAction::Noop);
+        assert!(diffs.is_empty());
+    }
+
+    #[test]
+    fn plan_summary_counts_actions() {
+        let change = |action| PlannedChange {
+            resource: "branch",
+            deployment: "d".to_string(),
+            name: "b".to_string(),
+            action,
+            deployment_id: None,
+            diffs: Vec::new(),
+            payload: ChangePayload::Branch(ManifestBranch {
+                name: "b".to_string(),
+                snapshot: None,
+                ephemeral: false,
+            }),
+        };
+        let plan = Plan::new(
+            "guepard.yaml".to_string(),
+            vec![
+                change(ChangeAction::Create),
+                change(ChangeAction::Noop),
+                change(ChangeAction::Noop),
+            ],
+        );
+        assert_eq!(plan.summary.create, 1);
+        assert_eq!(plan.summary.noop, 2);
+        assert!(plan.has_changes());
+
+        let value = serde_json::to_value(&plan).unwrap();
+        assert_eq!(value["changes"][0]["action"], "create");
+        assert!(value["changes"][0].get("payload").is_none());
+    }
+}
@@ -1,3 +1,6 @@
+use crate::application::commands::checkout::{
+    attached_branch_id, guard_discard, wants_discard, DiscardGuard,
+};
 use crate::application::dto::branch::BranchRequest;
 use crate::application::services::{branch, deploy};
 use crate::config::config::Config;
@@ -24,6 +27,9 @@ struct BranchRow {
     #[tabled(rename = "Ephemeral")]
     #[serde(rename = "is_ephemeral")]
     is_ephemeral: String,
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety_snapshot_id: Option<String>,
 }
 
 use crate::application::output::{
@@ -46,16 +52,37 @@ pub async fn branch(args: &BranchArgs, config: &Config, output_format: OutputFor
                 .clone()
                 .unwrap_or_else(|| "a7d373a3-4244-47b7-aacb-ad366f2520f6".to_string()); // Default to main branch
 
+            let guard = if wants_discard(args.discard_changes.as_deref()) {
+                // Never guess: the snapshot must be of the branch being discarded
+                let current_branch = attached_branch_id(deployment_id, config).await?;
+                guard_discard(
+                    deployment_id,
+                    &current_branch,
+                    args.no_safety_snapshot,
+                    config,
+                    output_format,
+                )
+                .await?
+            } else {
+                DiscardGuard::default()
+            };
+
             let create_args = CreateBranchArgs {
                 deployment_id: deployment_id.clone(),
                 snapshot_id: args.snapshot_id.clone().unwrap(),
                 branch_name: name.clone(),
                 source_branch_id,
-                discard_changes: args.discard_changes.clone().unwrap_or("false".to_string()),
+                discard_changes: guard.discard_changes.to_string(),
                 checkout: args.checkout,
                 ephemeral: args.ephemeral,
             };
-            create(&create_args, config, output_format).await?;
+            create_with_guard(
+                &create_args,
+                guard.safety_snapshot_id,
+                config,
+                output_format,
+            )
+            .await?;
         } else {
             // List branches
             list(deployment_id, config, output_format).await?;
@@ -82,6 +109,15 @@ pub async fn create(
     args: &CreateBranchArgs,
     config: &Config,
     output_format: OutputFormat,
+) -> Result<()> {
+    create_with_guard(args, None, config, output_format).await
+}
+
+async fn create_with_guard(
+    args: &CreateBranchArgs,
+    safety_snapshot_id: Option<String>,
+    config: &Config,
+    output_format: OutputFormat,
 ) -> Result<()> {
     let request = BranchRequest {
         branch_name: Some(args.branch_name.clone()),
@@ -112,6 +148,7 @@ pub async fn create(
         } else {
             "No".to_string()
         },
+        safety_snapshot_id,
     };
 
     if output_format == OutputFormat::Table {
@@ -156,6 +193,7 @@ pub async fn list(deployment_id: &str, config: &Config, output_format: OutputFor
                 } else {
                     "No".to_string()
                 },
+                safety_snapshot_id: None,
             }
         })
         .collect();
@@ -0,0 +1,505 @@
+use crate::application::commands::compute::{
+    restart_and_confirm, RESIZE_WAIT_TIMEOUT_SECS, RESTART_STOP_TIMEOUT_SECS,
+};
+use crate::application::commands::deploy::purge_unattended;
+use crate::application::commands::log::parse_date_filter_at;
+use crate::application::commands::status::parse_api_timestamp;
+use crate::application::dto::deploy::ListDeploymentsResponse;
+use crate::application::output::{print_json, print_table_or_json, OutputFormat};
+use crate::application::services::{compute, deploy};
+use crate::config::config::Config;
+use crate::structure::BulkArgs;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Duration;
+use tabled::Tabled;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Fields of `ListDeploymentsResponse` that `--filter` can match on.
+const FILTER_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "repository_name",
+    "status",
+    "deployment_type",
+    "database_provider",
+    "database_version",
+    "fqdn",
+    "port",
+    "region",
+    "datacenter",
+    "created_date",
+    "created_by",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BulkAction {
+    Purge,
+    Start,
+    Stop,
+    Restart,
+}
+
+impl BulkAction {
+    fn describe(self) -> &'static str {
+        match self {
+            BulkAction::Purge => "purge deployment",
+            BulkAction::Start => "start compute",
+            BulkAction::Stop => "stop compute",
+            BulkAction::Restart => "restart compute",
+        }
+    }
+
+    fn done(self) -> &'static str {
+        match self {
+            BulkAction::Purge => "purged",
+            BulkAction::Start => "started",
+            BulkAction::Stop => "stopped",
+            BulkAction::Restart => "restarted",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FilterOp {
+    Equals,
+    NotEquals,
+    Matches,
+    NotMatches,
+}
+
+#[derive(Debug)]
+pub(crate) struct Filter {
+    field: String,
+    op: FilterOp,
+    value: String,
+    regex: Option<Regex>,
+}
+
+impl Filter {
+    /// Parse `field=value`, `field!=value`, `field~regex` or `field!~regex`.
+    pub(crate) fn parse(expr: &str) -> Result<Filter> {
+        let split = expr.find(['=', '~', '!']).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid filter '{}'. Use field=value, field!=value, field~regex or field!~regex",
+                expr
+            )
+        })?;
+        let field = expr[..split].trim().to_lowercase();
+        let rest = &expr[split..];
+        let (op, value) = if let Some(v) = rest.strip_prefix("!=") {
+            (FilterOp::NotEquals, v)
+        } else if let Some(v) = rest.strip_prefix("!~") {
+            (FilterOp::NotMatches, v)
+        } else if let Some(v) = rest.strip_prefix('=') {
+            (FilterOp::Equals, v)
+        } else if let Some(v) = rest.strip_prefix('~') {
+            (FilterOp::Matches, v)
+        } else {
+            return Err(anyhow::anyhow!("Invalid operator in filter '{}'", expr));
+        };
+
+        if !FILTER_FIELDS.contains(&field.as_str()) {
+            return Err(anyhow::anyhow!(
+                "Unknown filter field '{}'. Available: {}",
+                field,
+                FILTER_FIELDS.join(", ")
+            ));
+        }
+
+        let regex = match op {
+            FilterOp::Matches | FilterOp::NotMatches => Some(
+                Regex::new(value)
+                    .map_err(|e| anyhow::anyhow!("Invalid regex in filter '{}': {}", expr, e))?,
+            ),
+            _ => None,
+        };
+
+        Ok(Filter {
+            field,
+            op,
+            value: value.to_string(),
+            regex,
+        })
+    }
+
+    fn matches(&self, deployment: &ListDeploymentsResponse) -> bool {
+        let actual = field_value(deployment, &self.field).unwrap_or_default();
+        match self.op {
+            FilterOp::Equals => actual.eq_ignore_ascii_case(&self.value),
+            FilterOp::NotEquals => !actual.eq_ignore_ascii_case(&self.value),
+            FilterOp::Matches => self.regex.as_ref().is_some_and(|r| r.is_match(&actual)),
+            FilterOp::NotMatches => !self.regex.as_ref().is_some_and(|r| r.is_match(&actual)),
+        }
+    }
+}
+
+fn field_value(deployment: &ListDeploymentsResponse, field: &str) -> Option<String> {
+    let value = match field {
+        "id" => &deployment.id,
+        "name" => &deployment.name,
+        "repository_name" => &deployment.repository_name,
+        "status" => &deployment.status,
+        "deployment_type" => &deployment.deployment_type,
+        "database_provider" => &deployment.database_provider,
+        "database_version" => &deployment.database_version,
+        "fqdn" => &deployment.fqdn,
+        "port" => return deployment.port.map(|p| p.to_string()),
+        "region" => &deployment.region,
+        "datacenter" => &deployment.datacenter,
+        "created_date" => &deployment.created_date,
+        "created_by" => &deployment.created_by,
+        _ => return None,
+    };
+    Some(value.clone())
+}
+
+/// Whether any bulk selector was given (as opposed to a single `-x`).
+pub(crate) fn is_requested(args: &BulkArgs) -> bool {
+    args.all || !args.filters.is_empty() || args.status.is_some() || args.older_than.is_some()
+}
+
+/// Deployments matching every selector in `args`. `--older-than` accepts a
+/// compact age ("3d", "12h") or an absolute date.
+pub(crate) fn select<'a>(
+    deployments: &'a [ListDeploymentsResponse],
+    args: &BulkArgs,
+    now: DateTime<Utc>,
+) -> Result<Vec<&'a ListDeploymentsResponse>> {
+    let filters = args
+        .filters
+        .iter()
+        .map(|f| Filter::parse(f))
+        .collect::<Result<Vec<_>>>()?;
+    let cutoff = args
+        .older_than
+        .as_deref()
+        .map(|age| parse_date_filter_at(age, now))
+        .transpose()?;
+
+    Ok(deployments
+        .iter()
+        .filter(|d| filters.iter().all(|f| f.matches(d)))
+        .filter(|d| {
+            args.status
+                .as_deref()
+                .is_none_or(|s| d.status.eq_ignore_ascii_case(s))
+        })
+        .filter(|d| match cutoff {
+            // Deployments with an unreadable creation date are never selected by age
+            Some(cutoff) => parse_api_timestamp(&d.created_date)
+                .map(|created| created < cutoff)
+                .unwrap_or(false),
+            None => true,
+        })
+        .collect())
+}
+
+#[derive(Tabled, Serialize)]
+struct PlanRow {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Type")]
+    deployment_type: String,
+    #[tabled(rename = "Created")]
+    created_date: String,
+}
+
+#[derive(Tabled, Serialize, Debug)]
+pub(crate) struct BulkResult {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Result")]
+    status: String,
+    #[tabled(rename = "Message")]
+    message: String,
+}
+
+async fn run_action(action: BulkAction, deployment_id: &str, config: &Config) -> Result<()> {
+    match action {
+        BulkAction::Purge => purge_unattended(deployment_id, config).await.map(|_| ()),
+        BulkAction::Start => Ok(compute::start_compute(deployment_id, config).await?),
+        BulkAction::Stop => Ok(compute::stop_compute(deployment_id, config).await?),
+        BulkAction::Restart => Ok(restart_and_confirm(
+            deployment_id,
+            Duration::from_secs(RESTART_STOP_TIMEOUT_SECS),
+            Duration::from_secs(RESIZE_WAIT_TIMEOUT_SECS),
+            config,
+            None,
+        )
+        .await?),
+    }
+}
+
+/// Run `action` on each target with at most `concurrency` in flight. Results
+/// keep the order of `targets`.
+async fn run_all(
+    action: BulkAction,
+    targets: Vec<(String, String)>,
+    concurrency: usize,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Vec<BulkResult> {
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    // A task that panics takes its target with it; keep it to report the failure
+    let mut spawned = HashMap::new();
+    for (index, (id, name)) in targets.into_iter().enumerate() {
+        let semaphore = semaphore.clone();
+        let config = config.clone();
+        let target = (index, id.clone(), name.clone());
+        let handle = tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let result = run_action(action, &id, &config).await;
+            (index, id, name, result)
+        });
+        spawned.insert(handle.id(), target);
+    }
+
+    let mut results = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        let (index, id, name, result) = match joined {
+            Ok(done) => done,
+            Err(e) => {
+                let Some((index, id, name)) = spawned.remove(&e.id()) else {
+                    continue;
+                };
+                let result = Err(anyhow::anyhow!("Bulk task failed: {}", e));
+                (index, id, name, result)
+            }
+        };
+        if output_format == OutputFormat::Table {
+            match &result {
+                Ok(()) => println!(
+                    "  {} {} {}",
+                    "✓".green(),
+                    name.cyan(),
+                    action.done().dimmed()
+                ),
+                Err(e) => println!("  {} {} {}", "✗".red(), name.cyan(), e),
+            }
+        }
+        let (status, message) = match result {
+            Ok(()) => ("ok".to_string(), action.done().to_string()),
+            Err(e) => ("failed".to_string(), e.to_string()),
+        };
+        results.push((
+            index,
+            BulkResult {
+                id,
+                name,
+                status,
+                message,
+            },
+        ));
+    }
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Select deployments, show the combined plan, confirm, then run `action` on
+/// all of them with bounded concurrency.
+pub(crate) async fn bulk(
+    action: BulkAction,
+    args: &BulkArgs,
+    yes: bool,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    if output_format == OutputFormat::Json && !yes {
+        return Err(anyhow::anyhow!(
+            "Bulk operations with --json need --yes (there is no prompt in JSON mode)"
+        ));
+    }
+
+    let deployments = deploy::list_deployments(config).await?;
+    let selected = select(&deployments, args, Utc::now())?;
+
+    if selected.is_empty() {
+        if output_format == OutputFormat::Json {
+            print_json(&serde_json::json!({
+                "action": action.describe(),
+                "matched": 0,
+                "results": [],
+            }));
+        } else {
+            println!("{} No deployments match the selection.", "ℹ️".blue());
+        }
+        return Ok(());
+    }
+
+    if output_format == OutputFormat::Table {
+        println!(
+            "\n{} Plan: {} for {} deployment(s)",
+            "📋".blue(),
+            action.describe(),
+            selected.len()
+        );
+        let rows: Vec<PlanRow> = selected
+            .iter()
+            .map(|d| PlanRow {
+                id: d.id.clone(),
+                name: d.name.clone(),
+                status: d.status.clone(),
+                deployment_type: d.deployment_type.clone(),
+                created_date: d.created_date.clone(),
+            })
+            .collect();
+        print_table_or_json(rows, output_format);
+        println!();
+    }
+
+    if !yes {
+        if action == BulkAction::Purge {
+            println!(
+                "{} {} This action is irreversible. All data of these deployments will be permanently deleted.",
+                "⚠️".red().bold(),
+                "DESTRUCTIVE:".red().bold()
+            );
+        }
+        print!(
+            "{} Proceed with {} on {} deployment(s)? (y/N): ",
+            "⚠️".yellow(),
+            action.describe(),
+            selected.len()
+        );
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        if !input.trim().to_lowercase().starts_with('y') {
+            println!("{} Cancelled.", "ℹ️".blue());
+            return Ok(());
+        }
+        println!();
+    }
+
+    let targets: Vec<(String, String)> = selected
+        .iter()
+        .map(|d| (d.id.clone(), d.name.clone()))
+        .collect();
+    let matched = targets.len();
+    let results = run_all(action, targets, args.concurrency, config, output_format).await;
+    let failed = results.iter().filter(|r| r.status != "ok").count();
+
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "action": action.describe(),
+            "matched": matched,
+            "succeeded": matched - failed,
+            "failed": failed,
+            "results": results,
+        }));
+    } else {
+        println!();
+        print_table_or_json(results, output_format);
+    }
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!(
+            "{} of {} operations failed",
+            failed,
+            matched
+        ));
+    }
+    if output_format == OutputFormat::Table {
+        println!(
+            "{} {} deployment(s) {}",
+            "✅".green(),
+            matched,
+            action.done()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn deployment(name: &str, status: &str, created: &str) -> ListDeploymentsResponse {
+        ListDeploymentsResponse {
+            id: format!("id-{}", name),
+            name: name.to_string(),
+            repository_name: name.to_string(),
+            status: status.to_string(),
+            deployment_type: "REPOSITORY".to_string(),
+            database_provider: "PostgreSQL".to_string(),
+            database_version: "16".to_string(),
+            fqdn: format!("{}.guepard.run", name),
+            port: Some(5432),
+            connection_string: None,
+            region: "us-west".to_string(),
+            datacenter: "aws".to_string(),
+            created_date: created.to_string(),
+            created_by: "dev@example.com".to_string(),
+        }
+    }
+
+    fn bulk_args(filters: &[&str], status: Option<&str>, older_than: Option<&str>) -> BulkArgs {
+        BulkArgs {
+            all: false,
+            filters: filters.iter().map(|f| f.to_string()).collect(),
+            status: status.map(str::to_string),
+            older_than: older_than.map(str::to_string),
+            concurrency: 4,
+        }
+    }
+
+    #[test]
+    fn test_filter_parse_operators() {
+        assert_eq!(Filter::parse("name=ci-1").unwrap().op, FilterOp::Equals);
+        assert_eq!(
+            Filter::parse("status!=INIT").unwrap().op,
+            FilterOp::NotEquals
+        );
+        assert_eq!(Filter::parse("name~^ci-").unwrap().op, FilterOp::Matches);
+        assert_eq!(
+            Filter::parse("region!~^eu").unwrap().op,
+            FilterOp::NotMatches
+        );
+        assert!(Filter::parse("colour=red").is_err());
+        assert!(Filter::parse("name~(").is_err());
+        assert!(Filter::parse("name").is_err());
+    }
+
+    #[test]
+    fn test_select_combines_filters_status_and_age() {
+        let now = Utc.with_ymd_and_hms(2025, 10, 10, 12, 0, 0).unwrap();
+        let deployments = vec![
+            deployment("ci-old", "running", "2025-10-01T08:00:00Z"),
+            deployment("ci-new", "running", "2025-10-10T08:00:00Z"),
+            deployment("ci-stopped", "stopped", "2025-10-01T08:00:00Z"),
+            deployment("prod", "running", "2025-01-01T08:00:00Z"),
+        ];
+
+        let args = bulk_args(&["name~^ci-"], Some("RUNNING"), Some("3d"));
+        let names: Vec<&str> = select(&deployments, &args, now)
+            .unwrap()
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, ["ci-old"]);
+
+        let args = bulk_args(&["name!~^ci-"], None, None);
+        let names: Vec<&str> = select(&deployments, &args, now)
+            .unwrap()
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(names, ["prod"]);
+    }
+}
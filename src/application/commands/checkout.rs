@@ -1,10 +1,15 @@
+use crate::application::commands::log::parse_date_filter;
+use crate::application::commands::status::{is_pending, parse_api_timestamp};
+use crate::application::dto::branch::{CheckoutRequest, ListBranchesResponse};
+use crate::application::dto::commit::GetCommitResponse;
 use crate::application::output::{
     print_json, print_row_or_json, print_table_or_json, OutputFormat,
 };
-use crate::application::services::{branch, commit};
+use crate::application::services::{branch, commit, compute, deploy};
 use crate::config::config::Config;
 use crate::structure::{CheckoutArgs, CheckoutBranchArgs};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use serde::Serialize;
 use tabled::Tabled;
@@ -24,6 +29,9 @@ struct CheckoutRow {
     #[tabled(rename = "Comment")]
     #[serde(rename = "comment")]
     comment: String,
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety_snapshot_id: Option<String>,
 }
 
 pub async fn checkout(
@@ -32,16 +40,106 @@ pub async fn checkout(
     output_format: OutputFormat,
 ) -> Result<()> {
     if let Some(deployment_id) = &args.deployment_id {
+        let discard_requested = wants_discard(args.discard_changes.as_deref());
+
         if let Some(branch_id) = &args.branch_id {
             // Checkout specific branch
+            let guard = if discard_requested {
+                let current_branch = attached_branch_id(deployment_id, config).await?;
+                guard_discard(
+                    deployment_id,
+                    &current_branch,
+                    args.no_safety_snapshot,
+                    config,
+                    output_format,
+                )
+                .await?
+            } else {
+                DiscardGuard::default()
+            };
             let checkout_args = CheckoutBranchArgs {
                 deployment_id: deployment_id.clone(),
                 branch_id: branch_id.clone(),
             };
-            checkout_branch(&checkout_args, config, output_format).await?;
+            checkout_branch_with_guard(&checkout_args, guard, config, output_format).await?;
         } else if let Some(snapshot_id) = &args.snapshot_id {
             // Restore specific snapshot
-            restore_snapshot(deployment_id, snapshot_id, config, output_format).await?;
+            let current_branch = attached_branch_id(deployment_id, config).await?;
+            let guard = if discard_requested {
+                guard_discard(
+                    deployment_id,
+                    &current_branch,
+                    args.no_safety_snapshot,
+                    config,
+                    output_format,
+                )
+                .await?
+            } else {
+                DiscardGuard::default()
+            };
+            restore_snapshot(
+                deployment_id,
+                &current_branch,
+                snapshot_id,
+                guard,
+                config,
+                output_format,
+            )
+            .await?;
+        } else if let Some(at) = &args.at {
+            // Restore the newest snapshot at or before a point in time
+            let target = parse_date_filter(at)?;
+            let current_branch = attached_branch_id(deployment_id, config).await?;
+            let branch_id = match &args.branch {
+                Some(selector) => {
+                    let branches = branch::list_branches(deployment_id, config).await?;
+                    find_branch(&branches, selector)
+                        .map(|b| b.id.clone())
+                        .ok_or_else(|| anyhow::anyhow!("Branch '{}' not found", selector))?
+                }
+                None => current_branch.clone(),
+            };
+
+            let commits = commit::list_all_commits(deployment_id, config).await?;
+            let picked = select_snapshot_at(&commits, &branch_id, target).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No snapshot found on branch {} at or before {}",
+                    branch_id,
+                    target.format("%Y-%m-%d %H:%M:%S UTC")
+                )
+            })?;
+
+            if output_format == OutputFormat::Table {
+                println!(
+                    "{} Picked snapshot {} \"{}\" taken {}",
+                    "🕰️".blue(),
+                    picked.id.cyan(),
+                    picked.snapshot_comment,
+                    picked.created_date
+                );
+            }
+
+            let guard = if discard_requested {
+                guard_discard(
+                    deployment_id,
+                    &current_branch,
+                    args.no_safety_snapshot,
+                    config,
+                    output_format,
+                )
+                .await?
+            } else {
+                DiscardGuard::default()
+            };
+            restore_snapshot(
+                deployment_id,
+                &branch_id,
+                &picked.id,
+                guard,
+                config,
+                output_format,
+            )
+            .await?;
         } else {
             // List available branches for checkout
             if output_format == OutputFormat::Table {
@@ -74,27 +172,121 @@ pub async fn checkout(
     Ok(())
 }
 
+/// Outcome of the safety net taken before a discarding checkout.
+#[derive(Default)]
+pub(crate) struct DiscardGuard {
+    /// Whether the discard may be sent to the API.
+    pub discard_changes: bool,
+    pub safety_snapshot_id: Option<String>,
+}
+
+/// Interpret the string-valued `--discard-changes` flag.
+pub(crate) fn wants_discard(value: Option<&str>) -> bool {
+    matches!(
+        value.map(|v| v.trim().to_lowercase()).as_deref(),
+        Some("true") | Some("yes") | Some("1")
+    )
+}
+
+/// Resolve the branch currently attached to the deployment's compute.
+pub(crate) async fn attached_branch_id(deployment_id: &str, config: &Config) -> Result<String> {
+    match compute::list_compute(deployment_id, config).await {
+        Ok(compute) => Ok(compute.branch_id.unwrap_or(compute.attached_branch)),
+        Err(_) => {
+            let deployment = deploy::get_deployment(deployment_id, config).await?;
+            deployment
+                .branch_id
+                .ok_or_else(|| anyhow::anyhow!("No active branch found for deployment"))
+        }
+    }
+}
+
+/// Snapshot the current branch before uncommitted changes are discarded.
+///
+/// The discard is only allowed when the snapshot succeeded or the user opted out
+/// with `--no-safety-snapshot`; a failed snapshot is an error, not a silent
+/// fallback to keeping the changes.
+pub(crate) async fn guard_discard(
+    deployment_id: &str,
+    branch_id: &str,
+    skip_safety_snapshot: bool,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<DiscardGuard> {
+    if skip_safety_snapshot {
+        return Ok(DiscardGuard {
+            discard_changes: true,
+            safety_snapshot_id: None,
+        });
+    }
+
+    match commit::create_safety_snapshot(deployment_id, branch_id, config).await {
+        Ok(snapshot) => {
+            if output_format == OutputFormat::Table {
+                println!(
+                    "{} Safety snapshot created: {} (restore with 'guepard checkout -x {} -s {}')",
+                    "🛟".green(),
+                    snapshot.id.cyan(),
+                    deployment_id,
+                    snapshot.id
+                );
+            }
+            Ok(DiscardGuard {
+                discard_changes: true,
+                safety_snapshot_id: Some(snapshot.id),
+            })
+        }
+        Err(e) => Err(anyhow::anyhow!(
+            "Safety snapshot of branch {} failed ({}); nothing was discarded. Retry, or pass --no-safety-snapshot to discard without one",
+            branch_id,
+            e
+        )),
+    }
+}
+
+/// Match a branch by ID, branch name or label.
+pub(crate) fn find_branch<'a>(
+    branches: &'a [ListBranchesResponse],
+    selector: &str,
+) -> Option<&'a ListBranchesResponse> {
+    branches.iter().find(|b| {
+        b.id == selector
+            || b.branch_name.as_deref() == Some(selector)
+            || b.label_name.as_deref() == Some(selector)
+    })
+}
+
+/// Newest ready snapshot on `branch_id` created at or before `at`.
+fn select_snapshot_at<'a>(
+    commits: &'a [GetCommitResponse],
+    branch_id: &str,
+    at: DateTime<Utc>,
+) -> Option<&'a GetCommitResponse> {
+    commits
+        .iter()
+        .filter(|c| c.dataset_id.as_deref() == Some(branch_id) && !is_pending(&c.status))
+        .filter_map(|c| parse_api_timestamp(&c.created_date).map(|created| (created, c)))
+        .filter(|(created, _)| *created <= at)
+        .max_by_key(|(created, _)| *created)
+        .map(|(_, c)| c)
+}
+
 async fn restore_snapshot(
     deployment_id: &str,
+    branch_id: &str,
     snapshot_id: &str,
+    guard: DiscardGuard,
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    // Get branch ID from compute or deployment
-    let branch_id = match crate::application::services::compute::list_compute(deployment_id, config)
-        .await
-    {
-        Ok(compute) => compute.branch_id.or(Some(compute.attached_branch)).unwrap(),
-        Err(_) => {
-            let deployment =
-                crate::application::services::deploy::get_deployment(deployment_id, config).await?;
-            deployment
-                .branch_id
-                .ok_or_else(|| anyhow::anyhow!("No active branch found for deployment"))?
-        }
-    };
-
-    let branch = branch::checkout_snapshot(deployment_id, &branch_id, snapshot_id, config).await?;
+    let branch = branch::checkout_snapshot(
+        deployment_id,
+        branch_id,
+        snapshot_id,
+        guard.discard_changes,
+        config,
+    )
+    .await?;
 
     let snapshot_comment =
         if let Ok(snapshots) = commit::list_all_commits(deployment_id, config).await {
@@ -115,6 +307,7 @@ async fn restore_snapshot(
             .snapshot_id
             .unwrap_or_else(|| branch.branch_id.unwrap_or_else(|| branch.id.clone())),
         comment: snapshot_comment,
+        safety_snapshot_id: guard.safety_snapshot_id,
     };
 
     if output_format == OutputFormat::Table {
@@ -129,8 +322,21 @@ pub async fn checkout_branch(
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
+    checkout_branch_with_guard(args, DiscardGuard::default(), config, output_format).await
+}
+
+async fn checkout_branch_with_guard(
+    args: &CheckoutBranchArgs,
+    guard: DiscardGuard,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let request = guard.discard_changes.then_some(CheckoutRequest {
+        snapshot_id: None,
+        discard_changes: Some(true),
+    });
     let branch =
-        branch::checkout_branch(&args.deployment_id, &args.branch_id, None, config).await?;
+        branch::checkout_branch(&args.deployment_id, &args.branch_id, request, config).await?;
 
     let snapshot_id = branch
         .snapshot_id
@@ -155,6 +361,7 @@ pub async fn checkout_branch(
         status: branch.job_status.unwrap_or_default(),
         snapshot_id,
         comment: snapshot_comment,
+        safety_snapshot_id: guard.safety_snapshot_id,
     };
 
     if output_format == OutputFormat::Table {
@@ -205,6 +412,7 @@ async fn list_branches_for_checkout(
                 status: b.job_status.as_ref().map(|s| s.clone()).unwrap_or_default(),
                 snapshot_id,
                 comment: snapshot_comment,
+                safety_snapshot_id: None,
             }
         })
         .collect();
@@ -219,3 +427,54 @@ async fn list_branches_for_checkout(
     print_table_or_json(rows, output_format);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(id: &str, branch: &str, status: &str, created: &str) -> GetCommitResponse {
+        GetCommitResponse {
+            id: id.to_string(),
+            name: id.to_string(),
+            status: status.to_string(),
+            dataset_id: Some(branch.to_string()),
+            parent_id: None,
+            created_by: "tester".to_string(),
+            created_date: created.to_string(),
+            snapshot_type: "MANUAL".to_string(),
+            is_ephemeral: false,
+            snapshot_comment: String::new(),
+            schema: None,
+        }
+    }
+
+    #[test]
+    fn test_select_snapshot_at_picks_newest_before_target() {
+        let commits = vec![
+            snapshot("s1", "main", "CREATED", "2025-06-01T10:00:00Z"),
+            snapshot("s2", "main", "CREATED", "2025-06-01T13:59:00Z"),
+            snapshot("s3", "main", "CREATED", "2025-06-01T15:00:00Z"),
+            snapshot("s4", "dev", "CREATED", "2025-06-01T13:59:30Z"),
+            snapshot("s5", "main", "IN_PROGRESS", "2025-06-01T13:59:50Z"),
+        ];
+        let at = parse_date_filter("2025-06-01 14:00").unwrap();
+
+        let picked = select_snapshot_at(&commits, "main", at).unwrap();
+        assert_eq!(picked.id, "s2");
+        assert_eq!(select_snapshot_at(&commits, "dev", at).unwrap().id, "s4");
+
+        let too_early = parse_date_filter("2025-05-31").unwrap();
+        assert!(select_snapshot_at(&commits, "main", too_early).is_none());
+    }
+
+    #[test]
+    fn test_wants_discard() {
+        assert!(wants_discard(Some("true")));
+        assert!(wants_discard(Some(" TRUE ")));
+        assert!(wants_discard(Some("yes")));
+        assert!(wants_discard(Some("1")));
+        assert!(!wants_discard(Some("false")));
+        assert!(!wants_discard(Some("")));
+        assert!(!wants_discard(None));
+    }
+}
@@ -1,7 +1,11 @@
+use crate::application::commands::wait::{self, WaitSpec, DEFAULT_WAIT_TIMEOUT};
 use crate::application::dto::clone::CreateCloneRequest;
-use crate::application::output::{print_json, OutputFormat};
+use crate::application::output::{
+    print_json, redact_uri, secret, secrets_shown, OutputFormat, REDACTED,
+};
+use crate::application::services::usage::{self, QuotaResource};
 use crate::application::services::{clone, compute, deploy, performance};
-use crate::config::config::Config;
+use crate::config::config::{save_database_password, Config};
 use crate::structure::CloneArgs;
 use anyhow::Result;
 use colored::Colorize;
@@ -79,6 +83,8 @@ async fn create_clone(
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
+    usage::ensure_quota(QuotaResource::Clone, config).await?;
+
     // Get deployment info to get database_provider and database_version for performance profile lookup
     let deployment = deploy::get_deployment(deployment_id, config).await?;
 
@@ -113,6 +119,13 @@ async fn create_clone(
 
     let clone_response = clone::create_clone(deployment_id, snapshot_id, request, config).await?;
 
+    if args.wait {
+        let spec = WaitSpec::compute_running(&clone_response.id, DEFAULT_WAIT_TIMEOUT);
+        wait::wait_for(&spec, config, output_format == OutputFormat::Table)
+            .await?
+            .check()?;
+    }
+
     // Try to get compute information for the real port
     let compute_data = match compute::list_compute(&clone_response.id, config).await {
         Ok(compute_info) => Some(compute_info),
@@ -128,11 +141,18 @@ async fn create_clone(
         .database_username
         .clone()
         .unwrap_or_else(|| "N/A".to_string());
-    let password = clone_response
+    let mut password = clone_response
         .database_password
         .clone()
         .unwrap_or_else(|| "N/A".to_string());
 
+    // Keep the password out of the output entirely once it is stored locally
+    if args.secret_to_keyring && clone_response.database_password.is_some() {
+        let location = save_database_password(&clone_response.id, &password)?;
+        password = REDACTED.to_string();
+        eprintln!("{} Database password stored in {}", "🔐".green(), location);
+    }
+
     let connection_uri = format!(
         "postgresql://{}:{}@{}:{}/{}",
         username, password, clone_response.fqdn, port, clone_response.repository_name
@@ -375,16 +395,22 @@ async fn create_clone(
 
     // Show username and password (already calculated)
     println!("  {} {}", "Username:".yellow(), username);
-    println!("  {} {}", "Password:".yellow(), password);
+    println!("  {} {}", "Password:".yellow(), secret(&password));
+    if !secrets_shown() && !args.secret_to_keyring && password != "N/A" {
+        println!(
+            "  {}",
+            "Password hidden; add --show-secrets to reveal it".dimmed()
+        );
+    }
 
     // Construct database connection URI if we have both username and password (and they're not "N/A")
     if username != "N/A" && password != "N/A" {
         println!();
         println!("{} Ready-to-use Connection URI:", "💡".green());
-        println!("{}", connection_uri.cyan().bold());
+        println!("{}", redact_uri(&connection_uri).cyan().bold());
         println!();
         println!("{} Connect with psql:", "📝".yellow());
-        println!("{} psql '{}'", "  $".dimmed(), connection_uri);
+        println!("{} psql '{}'", "  $".dimmed(), redact_uri(&connection_uri));
         println!();
         println!(
             "{} Connect with any PostgreSQL client using the URI above",
@@ -1,6 +1,7 @@
 use crate::application::dto::commit::CreateCommitRequest;
 use crate::application::output::{print_row_or_json, OutputFormat};
 use crate::application::services::commit;
+use crate::application::services::usage::{self, QuotaResource};
 use crate::config::config::Config;
 use crate::structure::CommitArgs;
 use anyhow::Result;
@@ -23,6 +24,8 @@ struct CommitRow {
 }
 
 pub async fn commit(args: &CommitArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    usage::ensure_quota(QuotaResource::Snapshot, config).await?;
+
     let request = CreateCommitRequest {
         snapshot_comment: args.message.clone(),
     };
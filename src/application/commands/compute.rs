@@ -1,13 +1,32 @@
-use crate::application::output::{print_json, print_row_or_json, OutputFormat};
-use crate::application::services::{branch, compute};
-use crate::config::config::Config;
+use crate::application::commands::bulk::{self, BulkAction};
+use crate::application::commands::checkout::find_branch;
+use crate::application::commands::deploy::{computes_to_stop, ComputeToStop};
+use crate::application::commands::status::{format_age, parse_api_timestamp};
+use crate::application::commands::wait::{self, WaitSpec};
+use crate::application::dto::compute::{ComputeState, ComputeStatusResponse};
+use crate::application::output::{print_json, print_row_or_json, redact_uri, OutputFormat};
+use crate::application::services::{branch, compute, deploy, performance};
+use crate::config::config::{resolve_deployment_id, Config};
 use crate::domain::errors::compute_error::ComputeError;
+use crate::domain::errors::exit_error::ExitError;
 use crate::structure::ComputeArgs;
 use anyhow::Result;
-use colored::Colorize;
+use chrono::Utc;
+use colored::{ColoredString, Colorize};
+use indicatif::{ProgressBar, ProgressStyle};
 use serde::Serialize;
+use std::time::Duration;
 use tabled::Tabled;
 
+/// Default time to wait for the compute to come back after a profile change.
+pub(crate) const RESIZE_WAIT_TIMEOUT_SECS: u64 = 300;
+
+/// Default time to wait for the compute to go down during a restart.
+pub(crate) const RESTART_STOP_TIMEOUT_SECS: u64 = 120;
+
+/// How long a profile change that needs a restart may take to show it.
+const RESIZE_RESTART_NOTICE_SECS: u64 = 60;
+
 #[derive(Tabled, Serialize)]
 struct ComputeRow {
     #[tabled(rename = "Deployment ID")]
@@ -36,12 +55,25 @@ struct StatusRow {
     status: String,
     #[tabled(rename = "Message")]
     message: String,
+    #[tabled(rename = "Uptime")]
+    uptime: String,
+    #[tabled(rename = "Attached Branch")]
+    branch: String,
+    #[tabled(rename = "Profile")]
+    profile: String,
 }
 
 #[derive(Serialize)]
 struct StatusWithConnection {
-    status: String,
-    message: String,
+    status: ComputeState,
+    healthy: bool,
+    message: Option<String>,
+    started_at: Option<String>,
+    uptime_seconds: Option<i64>,
+    attached_branch_id: Option<String>,
+    attached_branch: Option<String>,
+    performance_profile_id: Option<String>,
+    performance_profile: Option<String>,
     connection_string: Option<String>,
 }
 
@@ -50,157 +82,519 @@ pub async fn compute(
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
+    if bulk::is_requested(&args.bulk) {
+        let action = match args.action.as_deref() {
+            Some("start") => BulkAction::Start,
+            Some("stop") => BulkAction::Stop,
+            Some("restart") => BulkAction::Restart,
+            other => {
+                return Err(anyhow::anyhow!(
+                "--all/--filter/--status/--older-than work with start, stop and restart, not '{}'",
+                other.unwrap_or("list")
+            ))
+            }
+        };
+        return bulk::bulk(action, &args.bulk, args.yes, config, output_format).await;
+    }
+
+    if args.all_shadows && args.action.as_deref() != Some("restart") {
+        return Err(anyhow::anyhow!("--all-shadows only works with restart"));
+    }
+
+    let deployment_id = &resolve_deployment_id(args.deployment_id.as_deref())?;
     match args.action.as_deref() {
-        Some("status") => status(args, config, output_format).await,
-        Some("start") => start(args, config, output_format).await,
-        Some("stop") => stop(args, config, output_format).await,
+        Some("status") => status(deployment_id, config, output_format).await,
+        Some("start") => {
+            start(deployment_id, config, output_format).await?;
+            wait_if_requested(deployment_id, args, config, output_format).await
+        }
+        Some("stop") => stop(deployment_id, config, output_format).await,
         Some("restart") => restart(args, config, output_format).await,
-        Some("logs") => logs(args, config, output_format).await,
+        Some("logs") => logs(deployment_id, config, output_format).await,
+        Some("resize") => match &args.performance_profile {
+            Some(label) => {
+                resize(
+                    deployment_id,
+                    label,
+                    args.wait_timeout,
+                    config,
+                    output_format,
+                )
+                .await
+            }
+            None => Err(anyhow::anyhow!(
+                "resize requires --performance-profile <label> (see 'guepard list performance')"
+            )),
+        },
         Some(action) => {
             if output_format == OutputFormat::Table {
                 println!("{} Unknown action: {}", "❌".red(), action);
-                println!("Available actions: start, stop, status, logs");
+                println!("Available actions: start, stop, status, logs, list, resize");
             } else {
                 print_json(&serde_json::json!({
                     "error": format!("Unknown action: {}", action),
-                    "available_actions": ["start", "stop", "status", "logs", "list"]
+                    "available_actions": ["start", "stop", "status", "logs", "list", "resize"]
                 }));
             }
             Ok(())
         }
         None => {
             // Default action: show compute info (like list)
-            list(args, config, output_format).await
+            list(deployment_id, config, output_format).await
         }
     }
 }
 
+/// Seconds since the compute started, from the API's uptime or its start time.
+fn uptime_seconds(status: &ComputeStatusResponse) -> Option<i64> {
+    if let Some(uptime) = status.uptime {
+        return i64::try_from(uptime).ok();
+    }
+    status
+        .started_at
+        .as_deref()
+        .and_then(parse_api_timestamp)
+        .map(|started| {
+            Utc::now()
+                .signed_duration_since(started)
+                .num_seconds()
+                .max(0)
+        })
+}
+
+fn state_label(state: ComputeState) -> ColoredString {
+    match state {
+        ComputeState::Running => state.to_string().green().bold(),
+        ComputeState::Pending | ComputeState::Starting | ComputeState::Stopping => {
+            state.to_string().yellow().bold()
+        }
+        ComputeState::Stopped => state.to_string().dimmed().bold(),
+        ComputeState::Failed | ComputeState::Unknown => state.to_string().red().bold(),
+    }
+}
+
+/// Show the compute's lifecycle state. Exits with the state's own exit code
+/// (see `ComputeState::exit_code`) when the compute is not running.
 pub async fn status(
-    args: &ComputeArgs,
+    deployment_id: &str,
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let compute_info = compute::list_compute(&args.deployment_id, config)
-        .await
-        .ok();
-    let connection_string = compute_info.as_ref().map(|c| c.connection_string.clone());
-
-    match compute::get_status(&args.deployment_id, config).await {
-        Ok(result) => {
-            let message = result
-                .message
-                .unwrap_or_else(|| "Compute instance is running".to_string());
-
-            if output_format == OutputFormat::Json {
-                let status_data = StatusWithConnection {
-                    status: "Healthy".to_string(),
-                    message,
-                    connection_string,
-                };
-                print_json(&status_data);
-            } else {
-                let status_row = StatusRow {
-                    status: "Healthy".to_string(),
-                    message,
-                };
-
-                println!(
-                    "{} Compute Status for deployment: {}",
-                    "📊".blue(),
-                    args.deployment_id
-                );
-                print_row_or_json(status_row, output_format);
-
-                if let Some(conn_str) = connection_string {
-                    println!();
-                    println!("{} Connection Information", "🔗".blue());
-                    println!(
-                        "  {} {}",
-                        "Connection URI:".yellow(),
-                        conn_str.cyan().bold()
-                    );
-                }
-            }
+    let (state, result) = compute::get_state(deployment_id, config).await?;
+    let compute_info = compute::list_compute(deployment_id, config).await.ok();
+
+    // Branch and profile names are best effort; the IDs are shown when the
+    // lookups fail
+    let attached_branch_id = compute_info.as_ref().map(|c| {
+        c.branch_id
+            .clone()
+            .unwrap_or_else(|| c.attached_branch.clone())
+    });
+    let attached_branch = match &attached_branch_id {
+        Some(id) => branch::list_branches(deployment_id, config)
+            .await
+            .ok()
+            .and_then(|branches| {
+                find_branch(&branches, id)
+                    .and_then(|b| b.branch_name.clone().or(b.label_name.clone()))
+            }),
+        None => None,
+    };
+    let performance_profile_id = compute_info
+        .as_ref()
+        .map(|c| c.performance_profile_id.clone())
+        .filter(|id| !id.is_empty());
+    let performance_profile = match &performance_profile_id {
+        Some(id) => performance::list_performance_profiles(config)
+            .await
+            .ok()
+            .and_then(|profiles| {
+                profiles
+                    .into_iter()
+                    .find(|p| &p.id == id)
+                    .map(|p| p.label_name)
+            }),
+        None => None,
+    };
+
+    let report = StatusWithConnection {
+        status: state,
+        healthy: state == ComputeState::Running,
+        message: result.message.clone(),
+        started_at: result.started_at.clone(),
+        uptime_seconds: uptime_seconds(&result),
+        attached_branch_id,
+        attached_branch,
+        performance_profile_id,
+        performance_profile,
+        connection_string: compute_info.map(|c| c.connection_string),
+    };
+
+    if output_format == OutputFormat::Json {
+        print_json(&report);
+    } else {
+        println!(
+            "{} Compute Status for deployment: {}",
+            "📊".blue(),
+            deployment_id
+        );
+        let status_row = StatusRow {
+            status: state_label(state).to_string(),
+            message: report.message.clone().unwrap_or_else(|| match state {
+                ComputeState::Running => "Compute instance is running".to_string(),
+                _ => "-".to_string(),
+            }),
+            uptime: report
+                .uptime_seconds
+                .filter(|_| state == ComputeState::Running)
+                .map(format_age)
+                .unwrap_or_else(|| "-".to_string()),
+            branch: match (&report.attached_branch, &report.attached_branch_id) {
+                (Some(name), Some(id)) => format!("{} ({})", name, id),
+                (None, Some(id)) => id.clone(),
+                _ => "-".to_string(),
+            },
+            profile: report
+                .performance_profile
+                .clone()
+                .or(report.performance_profile_id.clone())
+                .unwrap_or_else(|| "-".to_string()),
+        };
+        print_row_or_json(status_row, output_format);
+
+        if let Some(conn_str) = &report.connection_string {
+            println!();
+            println!("{} Connection Information", "🔗".blue());
+            println!(
+                "  {} {}",
+                "Connection URI:".yellow(),
+                redact_uri(conn_str).cyan().bold()
+            );
         }
-        Err(ComputeError::NotHealthy(message)) => {
-            if output_format == OutputFormat::Json {
-                let status_data = StatusWithConnection {
-                    status: "Not Healthy".to_string(),
-                    message,
-                    connection_string,
-                };
-                print_json(&status_data);
-            } else {
-                let status_row = StatusRow {
-                    status: "Not Healthy".to_string(),
-                    message,
-                };
-
-                println!(
-                    "{} Compute Status for deployment: {}",
-                    "📊".blue(),
-                    args.deployment_id
-                );
-                print_row_or_json(status_row, output_format);
-
-                if let Some(conn_str) = connection_string {
-                    println!();
-                    println!("{} Connection Information", "🔗".blue());
-                    println!(
-                        "  {} {}",
-                        "Connection URI:".yellow(),
-                        conn_str.cyan().bold()
-                    );
-                }
-            }
+    }
+
+    if state != ComputeState::Running {
+        return Err(ExitError::ComputeState {
+            state: state.to_string(),
+            code: state.exit_code(),
         }
-        Err(e) => return Err(e.into()),
+        .into());
     }
     Ok(())
 }
 
-pub async fn start(args: &ComputeArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
-    compute::start_compute(&args.deployment_id, config).await?;
+pub async fn start(
+    deployment_id: &str,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    compute::start_compute(deployment_id, config).await?;
     if output_format == OutputFormat::Table {
         println!("{} Compute instance started successfully!", "✅".green());
     } else {
-        print_json(&serde_json::json!({"status": "started", "deployment_id": args.deployment_id}));
+        print_json(&serde_json::json!({"status": "started", "deployment_id": deployment_id}));
     }
     Ok(())
 }
 
-pub async fn stop(args: &ComputeArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
-    compute::stop_compute(&args.deployment_id, config).await?;
+/// With --wait, block until the compute reports running.
+async fn wait_if_requested(
+    deployment_id: &str,
+    args: &ComputeArgs,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    if !args.wait {
+        return Ok(());
+    }
+    let spec = WaitSpec::compute_running(deployment_id, Duration::from_secs(args.wait_timeout));
+    let report = wait::wait_for(&spec, config, output_format == OutputFormat::Table).await?;
+    report.check()?;
+    Ok(())
+}
+
+pub async fn stop(deployment_id: &str, config: &Config, output_format: OutputFormat) -> Result<()> {
+    compute::stop_compute(deployment_id, config).await?;
     if output_format == OutputFormat::Table {
         println!("{} Compute instance stopped successfully!", "✅".green());
     } else {
-        print_json(&serde_json::json!({"status": "stopped", "deployment_id": args.deployment_id}));
+        print_json(&serde_json::json!({"status": "stopped", "deployment_id": deployment_id}));
     }
     Ok(())
 }
 
+#[derive(Serialize)]
+struct RestartResult {
+    id: String,
+    name: String,
+    role: &'static str,
+    status: String,
+    message: Option<String>,
+}
+
+/// Stop the compute and wait until it is down, then start it and wait until it
+/// runs. If the stop does not finish within `stop_timeout`, start the compute
+/// again so it is not left half stopped, and report the timeout.
+pub(crate) async fn restart_and_confirm(
+    deployment_id: &str,
+    stop_timeout: Duration,
+    start_timeout: Duration,
+    config: &Config,
+    pb: Option<&ProgressBar>,
+) -> Result<(), ComputeError> {
+    if let Some(pb) = pb {
+        pb.set_message(format!("Stopping compute {}…", deployment_id));
+    }
+    compute::stop_compute(deployment_id, config).await?;
+    if let Err(stop_error) =
+        compute::wait_for_state(deployment_id, config, ComputeState::Stopped, stop_timeout).await
+    {
+        if let ComputeError::SessionError(_) = stop_error {
+            return Err(stop_error);
+        }
+        if let Some(pb) = pb {
+            pb.set_message(format!(
+                "Stop timed out; starting compute {} again…",
+                deployment_id
+            ));
+        }
+        let rollback = match compute::start_compute(deployment_id, config).await {
+            Ok(()) => "started it again".to_string(),
+            Err(e) => format!("starting it again also failed: {}", e),
+        };
+        return Err(ComputeError::Timeout(format!(
+            "{}; {}",
+            stop_error, rollback
+        )));
+    }
+
+    if let Some(pb) = pb {
+        pb.set_message(format!("Starting compute {}…", deployment_id));
+    }
+    compute::start_compute(deployment_id, config).await?;
+    compute::wait_for_state(deployment_id, config, ComputeState::Running, start_timeout).await?;
+    Ok(())
+}
+
 pub async fn restart(
     args: &ComputeArgs,
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    compute::stop_compute(&args.deployment_id, config).await?;
-    compute::start_compute(&args.deployment_id, config).await?;
-    if output_format == OutputFormat::Table {
-        println!("{} Compute instance restarted successfully!", "✅".green());
+    let deployment_id = resolve_deployment_id(args.deployment_id.as_deref())?;
+    let targets = if args.all_shadows {
+        let deployment = deploy::get_deployment(&deployment_id, config).await?;
+        if deployment.deployment_type != "F2" && output_format == OutputFormat::Table {
+            println!(
+                "{} {} is not an F2 deployment; it has no shadows to restart",
+                "ℹ️".blue(),
+                deployment_id
+            );
+        }
+        computes_to_stop(&deployment, config).await
     } else {
-        print_json(
-            &serde_json::json!({"status": "restarted", "deployment_id": args.deployment_id}),
+        vec![ComputeToStop {
+            id: deployment_id.clone(),
+            name: deployment_id.clone(),
+            role: "principal",
+        }]
+    };
+
+    let stop_timeout = Duration::from_secs(args.stop_timeout);
+    let start_timeout = Duration::from_secs(args.wait_timeout);
+    let mut results = Vec::new();
+    for target in targets {
+        let spinner = if output_format == OutputFormat::Table {
+            let pb = ProgressBar::new_spinner();
+            pb.set_style(
+                ProgressStyle::default_spinner()
+                    .template("{spinner:.dim} {msg} {elapsed:.dim}")
+                    .unwrap(),
+            );
+            pb.enable_steady_tick(Duration::from_millis(80));
+            Some(pb)
+        } else {
+            None
+        };
+
+        let outcome = restart_and_confirm(
+            &target.id,
+            stop_timeout,
+            start_timeout,
+            config,
+            spinner.as_ref(),
+        )
+        .await;
+        if let Some(pb) = spinner {
+            pb.finish_with_message(match &outcome {
+                Ok(()) => format!(
+                    "{} Restarted {} compute {}",
+                    "✓".green(),
+                    target.role,
+                    target.name.cyan()
+                ),
+                Err(e) => format!(
+                    "{} Restarting {} compute {} failed: {}",
+                    "✗".red(),
+                    target.role,
+                    target.name.cyan(),
+                    e
+                ),
+            });
+        }
+        // A session error will fail every remaining compute the same way
+        if let Err(ComputeError::SessionError(e)) = outcome {
+            return Err(ComputeError::SessionError(e).into());
+        }
+        results.push(RestartResult {
+            id: target.id,
+            name: target.name,
+            role: target.role,
+            status: if outcome.is_ok() { "ok" } else { "failed" }.to_string(),
+            message: outcome.err().map(|e| e.to_string()),
+        });
+    }
+
+    let failed = results.iter().filter(|r| r.status != "ok").count();
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "status": if failed == 0 { "restarted" } else { "failed" },
+            "deployment_id": deployment_id,
+            "computes": results,
+        }));
+    } else if failed == 0 {
+        println!("{} Compute instance restarted successfully!", "✅".green());
+    }
+
+    if failed > 0 {
+        return Err(ComputeError::Unexpected(format!(
+            "{} of {} compute(s) did not restart",
+            failed,
+            results.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Apply a performance profile to an existing deployment and wait until its
+/// compute is healthy again.
+pub(crate) async fn resize(
+    deployment_id: &str,
+    label: &str,
+    wait_timeout_secs: u64,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let deployment = deploy::get_deployment(deployment_id, config).await?;
+    let profiles = performance::list_performance_profiles(config).await?;
+    let profile = performance::find_profile_for_deployment(
+        &profiles,
+        label,
+        &deployment.database_provider,
+        &deployment.database_version,
+    )?;
+
+    let previous_profile_id = compute::list_compute(deployment_id, config)
+        .await
+        .ok()
+        .map(|c| c.performance_profile_id);
+    let previous_label = previous_profile_id.as_ref().and_then(|id| {
+        profiles
+            .iter()
+            .find(|p| &p.id == id)
+            .map(|p| p.label_name.clone())
+    });
+
+    if previous_profile_id.as_deref() == Some(profile.id.as_str()) {
+        if output_format == OutputFormat::Table {
+            println!(
+                "{} Deployment {} already uses performance profile {}",
+                "ℹ️".blue(),
+                deployment_id,
+                label.cyan()
+            );
+        } else {
+            print_json(&serde_json::json!({
+                "deployment_id": deployment_id,
+                "performance_profile": label,
+                "performance_profile_id": profile.id,
+                "previous_performance_profile": previous_label,
+                "changed": false,
+                "healthy": null
+            }));
+        }
+        return Ok(());
+    }
+
+    let response =
+        performance::apply_performance_profile(deployment_id, &profile.id, config).await?;
+
+    let spinner = if output_format == OutputFormat::Table {
+        println!(
+            "{} Applied performance profile {} ({} CPU, {} MB) to {}",
+            "✅".green(),
+            label.cyan(),
+            profile.min_cpu,
+            profile.min_memory,
+            deployment_id
+        );
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.dim} {msg}")
+                .unwrap(),
         );
+        pb.set_message("Waiting for compute to become healthy…");
+        pb.enable_steady_tick(Duration::from_millis(80));
+        Some(pb)
+    } else {
+        None
+    };
+
+    let timeout = Duration::from_secs(wait_timeout_secs);
+    let health = async {
+        // The first status after the change may still be the old, running one
+        if response.restart_required == Some(true) {
+            compute::wait_until_restarting(
+                deployment_id,
+                config,
+                timeout.min(Duration::from_secs(RESIZE_RESTART_NOTICE_SECS)),
+            )
+            .await?;
+        }
+        compute::wait_until_healthy(deployment_id, config, timeout).await
+    }
+    .await;
+
+    if let Some(pb) = spinner {
+        pb.finish_with_message(match &health {
+            Ok(_) => format!("{} {}", "✓".green(), "Compute is healthy"),
+            Err(_) => format!("{} {}", "⚠".yellow(), "Compute is not healthy yet"),
+        });
+    }
+
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "deployment_id": deployment_id,
+            "performance_profile": label,
+            "performance_profile_id": profile.id,
+            "previous_performance_profile": previous_label,
+            "changed": true,
+            "restart_required": response.restart_required,
+            "healthy": health.is_ok()
+        }));
     }
+
+    health?;
     Ok(())
 }
 
-pub async fn list(args: &ComputeArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
-    let result = compute::list_compute(&args.deployment_id, config).await?;
+pub async fn list(deployment_id: &str, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let result = compute::list_compute(deployment_id, config).await?;
 
     // Get branch information to find the current snapshot
-    let branches = branch::list_branches(&args.deployment_id, config).await?;
+    let branches = branch::list_branches(deployment_id, config).await?;
     let current_snapshot = branches
         .iter()
         .find(|b| b.id == result.attached_branch)
@@ -221,19 +615,19 @@ pub async fn list(args: &ComputeArgs, config: &Config, output_format: OutputForm
         println!(
             "{} Compute instance details for deployment: {}",
             "🖥️".blue(),
-            args.deployment_id
+            deployment_id
         );
     }
     print_row_or_json(compute_row, output_format);
     Ok(())
 }
 
-pub async fn logs(args: &ComputeArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
-    let result = compute::get_logs(&args.deployment_id, config).await?;
+pub async fn logs(deployment_id: &str, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let result = compute::get_logs(deployment_id, config).await?;
 
     if output_format == OutputFormat::Json {
         print_json(&serde_json::json!({
-            "deployment_id": args.deployment_id,
+            "deployment_id": deployment_id,
             "stdout": result.stdout_logs,
             "stderr": result.stderr_logs
         }));
@@ -241,7 +635,7 @@ pub async fn logs(args: &ComputeArgs, config: &Config, output_format: OutputForm
         println!(
             "{} Compute logs for deployment: {}",
             "📋".blue(),
-            args.deployment_id
+            deployment_id
         );
         println!("{}", "=".repeat(80).cyan());
 
@@ -262,3 +656,25 @@ pub async fn logs(args: &ComputeArgs, config: &Config, output_format: OutputForm
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uptime_seconds() {
+        let status = ComputeStatusResponse {
+            uptime: Some(90),
+            started_at: Some("2020-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(uptime_seconds(&status), Some(90));
+
+        let status = ComputeStatusResponse {
+            started_at: Some("2020-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        };
+        assert!(uptime_seconds(&status).unwrap() > 86400);
+        assert_eq!(uptime_seconds(&ComputeStatusResponse::default()), None);
+    }
+}
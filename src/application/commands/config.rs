@@ -4,15 +4,19 @@ use crate::config::config::{
 };
 use crate::domain::errors::config_error::ConfigError;
 use crate::structure::ConfigArgs;
-use base64;
 use colored::Colorize;
 
+use crate::application::auth::jwt_claims;
 use crate::application::output::{print_json, OutputFormat};
+use crate::application::services::usage::DEFAULT_QUOTA_WARN_THRESHOLD;
 
 pub async fn config(args: &ConfigArgs, output_format: OutputFormat) -> Result<(), ConfigError> {
     if args.show || args.get {
         show_config(output_format).await
-    } else if args.api_url.is_some() || args.app_url.is_some() {
+    } else if args.api_url.is_some()
+        || args.app_url.is_some()
+        || args.quota_warn_threshold.is_some()
+    {
         set_config(args, output_format).await
     } else {
         show_config(output_format).await
@@ -21,6 +25,9 @@ pub async fn config(args: &ConfigArgs, output_format: OutputFormat) -> Result<()
 
 async fn show_config(output_format: OutputFormat) -> Result<(), ConfigError> {
     let config_data = load_config_data()?;
+    let threshold = config_data
+        .quota_warn_threshold
+        .unwrap_or(DEFAULT_QUOTA_WARN_THRESHOLD);
     let logged_in = is_logged_in();
     let user = if logged_in {
         get_user_info_from_token().unwrap_or_else(|_| "Unknown".to_string())
@@ -32,6 +39,7 @@ async fn show_config(output_format: OutputFormat) -> Result<(), ConfigError> {
         print_json(&serde_json::json!({
             "api_url": config_data.api_url,
             "app_url": config_data.app_url,
+            "quota_warn_threshold": threshold,
             "logged_in": logged_in,
             "user": user
         }));
@@ -41,6 +49,7 @@ async fn show_config(output_format: OutputFormat) -> Result<(), ConfigError> {
     println!("⚙️  Current Configuration:");
     println!("   API URL: {}", config_data.api_url);
     println!("   App URL: {}", config_data.app_url);
+    println!("   Quota warning: {}%", threshold);
 
     // Show login status
     if logged_in {
@@ -56,6 +65,7 @@ async fn set_config(args: &ConfigArgs, output_format: OutputFormat) -> Result<()
     let existing = load_config_data().unwrap_or_else(|_| ConfigData {
         api_url: "https://api.guepard.run".to_string(),
         app_url: "https://app.guepard.run".to_string(),
+        quota_warn_threshold: None,
     });
 
     let api_url = args.api_url.as_deref().unwrap_or(&existing.api_url);
@@ -87,56 +97,36 @@ async fn set_config(args: &ConfigArgs, output_format: OutputFormat) -> Result<()
         }
     }
 
+    let quota_warn_threshold = args.quota_warn_threshold.or(existing.quota_warn_threshold);
+
     let config_data = ConfigData {
         api_url: api_url.to_string(),
         app_url: app_url.to_string(),
+        quota_warn_threshold,
     };
     save_config_data(&config_data)?;
 
+    let threshold = quota_warn_threshold.unwrap_or(DEFAULT_QUOTA_WARN_THRESHOLD);
     if output_format == OutputFormat::Json {
         print_json(&serde_json::json!({
             "status": "success",
             "api_url": api_url,
             "app_url": app_url,
+            "quota_warn_threshold": threshold,
             "message": "Configuration updated successfully"
         }));
     } else {
         println!("✅ Configuration updated successfully!");
         println!("   API URL: {}", api_url);
         println!("   App URL: {}", app_url);
+        println!("   Quota warning: {}%", threshold);
     }
     Ok(())
 }
 
 fn get_user_info_from_token() -> Result<String, ConfigError> {
     let token = load_jwt_token()?;
-
-    // JWT tokens have 3 parts separated by dots: header.payload.signature
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        return Err(ConfigError::IoError("Invalid JWT token format".to_string()));
-    }
-
-    // Decode the payload (second part)
-    let payload = parts[1];
-
-    // Add padding if needed for base64 decoding
-    let mut padded_payload = payload.to_string();
-    while padded_payload.len() % 4 != 0 {
-        padded_payload.push('=');
-    }
-
-    // Decode base64
-    let decoded =
-        base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &padded_payload)
-            .map_err(|e| ConfigError::IoError(format!("Failed to decode JWT payload: {}", e)))?;
-
-    let payload_str = String::from_utf8(decoded)
-        .map_err(|e| ConfigError::IoError(format!("Invalid UTF-8 in JWT payload: {}", e)))?;
-
-    // Parse JSON to extract user info
-    let payload_json: serde_json::Value = serde_json::from_str(&payload_str)
-        .map_err(|e| ConfigError::IoError(format!("Invalid JSON in JWT payload: {}", e)))?;
+    let payload_json = jwt_claims(&token)?;
 
     // Try to extract email or username from common JWT fields
     if let Some(email) = payload_json.get("email").and_then(|v| v.as_str()) {
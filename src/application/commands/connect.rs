@@ -0,0 +1,206 @@
+use crate::application::commands::connection::{
+    mysql_ssl_mode, resolve_params, ConnectionParams, Engine,
+};
+use crate::application::process::wait_in_foreground;
+use crate::config::config::{load_tenet_endpoint, resolve_deployment_id, Config};
+use crate::domain::errors::exit_error::ExitError;
+use crate::structure::ConnectArgs;
+use anyhow::Result;
+use colored::Colorize;
+use std::io::ErrorKind;
+use tokio::process::Command;
+
+/// A native client invocation. Secrets only ever go in `env`, never in `args`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ClientCommand {
+    pub program: &'static str,
+    pub args: Vec<String>,
+    pub env: Vec<(&'static str, String)>,
+}
+
+pub(crate) fn client_command(params: &ConnectionParams, extra_args: &[String]) -> ClientCommand {
+    let mut command = match params.engine {
+        Engine::PostgreSQL => {
+            let mut env = vec![("PGPASSWORD", params.password.clone())];
+            if let Some(mode) = &params.sslmode {
+                env.push(("PGSSLMODE", mode.clone()));
+            }
+            ClientCommand {
+                program: "psql",
+                args: vec![
+                    "-h".to_string(),
+                    params.host.clone(),
+                    "-p".to_string(),
+                    params.port.to_string(),
+                    "-U".to_string(),
+                    params.username.clone(),
+                    "-d".to_string(),
+                    params.database.clone(),
+                ],
+                env,
+            }
+        }
+        Engine::MySQL => {
+            let mut args = vec![
+                "-h".to_string(),
+                params.host.clone(),
+                "-P".to_string(),
+                params.port.to_string(),
+                "-u".to_string(),
+                params.username.clone(),
+            ];
+            if let Some(mode) = &params.sslmode {
+                args.push(format!("--ssl-mode={}", mysql_ssl_mode(mode)));
+            }
+            args.push(params.database.clone());
+            ClientCommand {
+                program: "mysql",
+                args,
+                env: vec![("MYSQL_PWD", params.password.clone())],
+            }
+        }
+        Engine::MongoDB => {
+            // mongosh has no password variable; it prompts when the URI carries
+            // a user without a password, which keeps the secret out of argv.
+            let mut without_password = params.clone();
+            without_password.password = String::new();
+            let uri = without_password.uri().replacen(":@", "@", 1);
+            ClientCommand {
+                program: "mongosh",
+                args: vec![uri],
+                env: Vec::new(),
+            }
+        }
+    };
+    command.args.extend(extra_args.iter().cloned());
+    command
+}
+
+/// Point the connection at a Tenet proxy instead of the database itself.
+fn route_via_tenet(params: &mut ConnectionParams, job_id: &str, args: &ConnectArgs) -> Result<()> {
+    if params.engine == Engine::MongoDB {
+        return Err(anyhow::anyhow!("Tenet proxies PostgreSQL and MySQL only"));
+    }
+
+    let cached = load_tenet_endpoint(job_id)
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    params.host = args.tenet_host.clone().or(cached.host).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown host for Tenet job {}. Pass --tenet-host (the address clients use to reach Tenet)",
+            job_id
+        )
+    })?;
+    params.port = args.tenet_port.or(cached.proxy_port).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown proxy port for Tenet job {}. Pass --tenet-port",
+            job_id
+        )
+    })?;
+    // Tenet's proxy port usually has no TLS
+    if params.sslmode.is_none() {
+        params.sslmode = Some("disable".to_string());
+    }
+    Ok(())
+}
+
+pub async fn connect(args: &ConnectArgs, config: &Config) -> Result<()> {
+    let deployment_id = resolve_deployment_id(args.deployment_id.as_deref())?;
+    let mut params = resolve_params(
+        &deployment_id,
+        args.branch.as_deref(),
+        args.clone.as_deref(),
+        config,
+    )
+    .await?;
+    params.sslmode = args.sslmode.clone();
+
+    if let Some(job_id) = &args.via_tenet {
+        route_via_tenet(&mut params, job_id, args)?;
+    }
+
+    let client = client_command(&params, &args.args);
+    eprintln!(
+        "{} Connecting to {}:{} as {} with {}",
+        "🔌".cyan(),
+        params.host,
+        params.port,
+        params.username,
+        client.program
+    );
+
+    let mut child = Command::new(client.program)
+        .args(&client.args)
+        .envs(client.env.iter().map(|(k, v)| (*k, v.as_str())))
+        .spawn()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => anyhow::anyhow!(
+                "{} not found on PATH. Install the client, or print the details with 'guepard connection -x {} --format psql-args'",
+                client.program,
+                deployment_id
+            ),
+            _ => anyhow::anyhow!("Failed to start {}: {}", client.program, e),
+        })?;
+    // Ctrl+C belongs to the client (cancel a query), not to guepard
+    let (status, _) = wait_in_foreground(&mut child).await?;
+
+    if !status.success() {
+        // Hand the client's exit status back to the caller's shell
+        return Err(ExitError::Child {
+            program: client.program.to_string(),
+            code: status.code().unwrap_or(1),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::connection::test_params;
+
+    #[test]
+    fn test_psql_password_only_in_env() {
+        let command = client_command(
+            &test_params("PostgreSQL", Some(6543), "s3cret"),
+            &["-c".into(), "select 1".into()],
+        );
+        assert_eq!(command.program, "psql");
+        assert_eq!(
+            command.args,
+            [
+                "-h",
+                "db.guepard.run",
+                "-p",
+                "6543",
+                "-U",
+                "guepard",
+                "-d",
+                "app",
+                "-c",
+                "select 1"
+            ]
+        );
+        assert_eq!(command.env, vec![("PGPASSWORD", "s3cret".to_string())]);
+    }
+
+    #[test]
+    fn test_mysql_and_mongosh_commands() {
+        let mut mysql = test_params("MySQL", Some(6543), "s3cret");
+        mysql.sslmode = Some("require".to_string());
+        let command = client_command(&mysql, &[]);
+        assert_eq!(command.program, "mysql");
+        assert!(command.args.contains(&"--ssl-mode=REQUIRED".to_string()));
+        assert_eq!(command.args.last().unwrap(), "app");
+        assert_eq!(command.env, vec![("MYSQL_PWD", "s3cret".to_string())]);
+
+        let command = client_command(&test_params("MongoDB", Some(6543), "s3cret"), &[]);
+        assert_eq!(
+            command.args,
+            ["mongodb://guepard@db.guepard.run:6543/app?authSource=admin"]
+        );
+        assert!(!command.args.iter().any(|a| a.contains("s3cret")));
+    }
+}
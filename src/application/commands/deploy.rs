@@ -1,8 +1,19 @@
+use crate::application::commands::bulk::{self, BulkAction};
+use crate::application::commands::compute::{resize, RESIZE_WAIT_TIMEOUT_SECS};
 use crate::application::commands::list;
-use crate::application::dto::deploy::{CreateDeploymentRequest, UpdateDeploymentRequest};
-use crate::application::output::{print_json, OutputFormat};
-use crate::application::services::{branch, clone, commit, compute, deploy, performance};
-use crate::config::config::Config;
+use crate::application::commands::wait::{self, WaitSpec, DEFAULT_WAIT_TIMEOUT};
+use crate::application::commands::wizard;
+use crate::application::dto::deploy::{
+    CreateDeploymentRequest, GetDeploymentResponse, UpdateDeploymentRequest,
+};
+use crate::application::dto::manifest::{ManifestDeployment, DEFAULT_PASSWORD_ENV};
+use crate::application::output::{
+    print_json, redact_uri, secret, secrets_shown, OutputFormat, REDACTED,
+};
+use crate::application::services::usage::{self, QuotaResource};
+use crate::application::services::{branch, clone, commit, compute, deploy, image, performance};
+use crate::config::config::{save_database_password, Config};
+use crate::domain::errors::compute_error::ComputeError;
 use crate::domain::errors::deploy_error::DeployError;
 use crate::structure::DeployArgs;
 use anyhow::Result;
@@ -10,6 +21,7 @@ use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
 use serde::Serialize;
 use std::io::{self, Write};
+use std::path::Path;
 use std::time::Duration;
 
 #[derive(Serialize)]
@@ -70,32 +82,64 @@ pub async fn deploy(args: &DeployArgs, config: &Config, output_format: OutputFor
         return interactive_deploy(config).await;
     }
 
+    if args.action.as_deref() == Some("purge") {
+        if !bulk::is_requested(&args.bulk) {
+            return Err(anyhow::anyhow!(
+                "'deploy purge' needs a selection: --all, --filter, --status or --older-than (for one deployment use -x <id> --purge)"
+            ));
+        }
+        return bulk::bulk(
+            BulkAction::Purge,
+            &args.bulk,
+            args.yes,
+            config,
+            output_format,
+        )
+        .await;
+    }
+
     if let Some(deployment_id) = &args.deployment_id {
         // We have a deployment ID, determine operation based on other args
-        if args.repository_name.is_some() {
-            // Update deployment
-            update_deployment(deployment_id, args, config, output_format).await?;
+        if args.repository_name.is_some() || args.performance_profile.is_some() {
+            if args.repository_name.is_some() {
+                // Update deployment
+                update_deployment(deployment_id, args, config, output_format).await?;
+            }
+            if let Some(label) = &args.performance_profile {
+                // Apply a new performance profile
+                resize(
+                    deployment_id,
+                    label,
+                    RESIZE_WAIT_TIMEOUT_SECS,
+                    config,
+                    output_format,
+                )
+                .await?;
+            }
         } else if args.purge {
             delete_deployment(deployment_id, args, config, output_format).await?;
+        } else if let Some(file) = &args.export_template {
+            export_template(deployment_id, file.as_deref(), config, output_format).await?;
         } else {
             // Get deployment details
             get_deployment(deployment_id, args, config, output_format).await?;
         }
     } else {
         // No deployment ID, check if we have create args
-        if args.database_provider.is_some()
-            && args.database_version.is_some()
-            && args.region.is_some()
-            && args.instance_type.is_some()
-            && args.datacenter.is_some()
-            && args.database_password.is_some()
+        if args.from_file.is_some()
+            || (((args.database_provider.is_some() && args.database_version.is_some())
+                || args.image_provider.is_some())
+                && args.region.is_some()
+                && args.instance_type.is_some()
+                && args.datacenter.is_some()
+                && args.database_password.is_some())
         {
             // Create new deployment
             create_deployment(args, config, output_format).await?;
         } else {
             if output_format == OutputFormat::Table {
                 println!("{} Please provide either:", "❌".red());
-                println!("  • Create args: -p, -v, -r, -i, -d, -w (and optionally -n, -u)");
+                println!("  • Create args: -p, -v (or --image-provider), -r, -i, -d, -w (and optionally -n, -u), or --from-file");
                 println!("  • Get/Update/Delete: -x <deployment_id> (and optionally -n for update, --purge for delete)");
                 println!(
                     "{} Use 'guepard deploy --help' for more information",
@@ -112,16 +156,152 @@ pub async fn deploy(args: &DeployArgs, config: &Config, output_format: OutputFor
     Ok(())
 }
 
+/// Check the requested provider/version (or --image-provider) against the image
+/// catalog so typos fail locally with a suggestion instead of an API 400.
+async fn resolve_database_image(
+    spec: &ManifestDeployment,
+    image_provider: Option<&str>,
+    config: &Config,
+) -> Result<(String, String)> {
+    let providers = match image::list_image_providers(config).await {
+        Ok(providers) => providers,
+        Err(e) if image_provider.is_none() => {
+            // Don't block deployments if the catalog itself is unavailable
+            eprintln!(
+                "{} Could not load image providers, skipping validation: {}",
+                "⚠️".yellow(),
+                e
+            );
+            return Ok((spec.provider.clone(), spec.version.clone()));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    if let Some(selector) = image_provider {
+        let image = image::find_image_provider(&providers, selector)?;
+        let catalog = &image.catalog;
+        if !spec.provider.is_empty() {
+            let provider = &spec.provider;
+            if !provider.eq_ignore_ascii_case(&catalog.database_provider) {
+                return Err(DeployError::ApiError(format!(
+                    "--database-provider {} does not match image {} ({})",
+                    provider, catalog.image_name, catalog.database_provider
+                ))
+                .into());
+            }
+        }
+        if !spec.version.is_empty() {
+            let version = &spec.version;
+            if *version != catalog.database_version {
+                return Err(DeployError::ApiError(format!(
+                    "--database-version {} does not match image {} ({})",
+                    version, catalog.image_name, catalog.database_version
+                ))
+                .into());
+            }
+        }
+        return Ok((
+            catalog.database_provider.clone(),
+            catalog.database_version.clone(),
+        ));
+    }
+
+    let image = image::resolve_image(&providers, &spec.provider, &spec.version)?;
+    Ok((
+        image.catalog.database_provider.clone(),
+        image.catalog.database_version.clone(),
+    ))
+}
+
+/// Settings for a new deployment: the `--from-file` request (if any) with the
+/// flags given on the command line taking precedence.
+fn deployment_spec(args: &DeployArgs) -> Result<ManifestDeployment> {
+    let mut spec = match &args.from_file {
+        Some(path) => {
+            let spec = ManifestDeployment::load_request(Path::new(path))?;
+            if !spec.branches.is_empty() || !spec.tenets.is_empty() {
+                eprintln!(
+                    "{} Ignoring branches and tenets in {}; use 'guepard apply' for those",
+                    "⚠️".yellow(),
+                    path
+                );
+            }
+            spec
+        }
+        None => ManifestDeployment {
+            name: "default-repo".to_string(),
+            provider: String::new(),
+            version: String::new(),
+            region: String::new(),
+            datacenter: String::new(),
+            deployment_type: "REPOSITORY".to_string(),
+            performance_profile: None,
+            username: None,
+            password_env: None,
+            node_id: None,
+            branches: Vec::new(),
+            tenets: Vec::new(),
+        },
+    };
+
+    let set = |field: &mut String, flag: &Option<String>| {
+        if let Some(value) = flag {
+            *field = value.clone();
+        }
+    };
+    set(&mut spec.name, &args.repository_name);
+    set(&mut spec.provider, &args.database_provider);
+    set(&mut spec.version, &args.database_version);
+    set(&mut spec.region, &args.region);
+    set(&mut spec.datacenter, &args.datacenter);
+    set(&mut spec.deployment_type, &args.instance_type);
+    if args.performance_profile.is_some() {
+        spec.performance_profile = args.performance_profile.clone();
+    }
+    if args.user.is_some() {
+        spec.username = args.user.clone();
+    }
+    if args.node_id.is_some() {
+        spec.node_id = args.node_id.clone();
+    }
+    Ok(spec)
+}
+
+/// `-w` wins; otherwise read the environment variable named by `password_env`.
+fn resolve_password(args: &DeployArgs, spec: &ManifestDeployment) -> Result<String> {
+    if let Some(password) = &args.database_password {
+        return Ok(password.clone());
+    }
+    match &spec.password_env {
+        Some(var) => std::env::var(var).map_err(|_| {
+            DeployError::ApiError(format!(
+                "Environment variable {} (password_env) is not set",
+                var
+            ))
+            .into()
+        }),
+        None => Err(DeployError::ApiError(
+            "No database password: pass -w or set password_env in the request file".to_string(),
+        )
+        .into()),
+    }
+}
+
 async fn create_deployment(
     args: &DeployArgs,
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let database_provider = args.database_provider.clone().unwrap();
-    let database_version = args.database_version.clone().unwrap();
+    let spec = deployment_spec(args)?;
+    let database_password = resolve_password(args, &spec)?;
+
+    usage::ensure_quota(QuotaResource::Deployment, config).await?;
+
+    let (database_provider, database_version) =
+        resolve_database_image(&spec, args.image_provider.as_deref(), config).await?;
 
     // Get performance profile ID
-    let performance_profile_label = args
+    let performance_profile_label = spec
         .performance_profile
         .clone()
         .unwrap_or_else(|| "gp.g1.xsmall".to_string());
@@ -134,25 +314,33 @@ async fn create_deployment(
     .await?;
 
     let request = CreateDeploymentRequest {
-        repository_name: args
-            .repository_name
-            .clone()
-            .unwrap_or("default-repo".to_string()),
+        repository_name: spec.name,
         database_provider,
         database_version,
-        deployment_type: args
-            .instance_type
-            .clone()
-            .unwrap_or("REPOSITORY".to_string()),
-        region: args.region.clone().unwrap(),
-        datacenter: args.datacenter.clone().unwrap(),
-        database_username: args.user.clone().unwrap_or("guepard".to_string()),
-        database_password: args.database_password.clone().unwrap(),
+        deployment_type: spec.deployment_type,
+        region: spec.region,
+        datacenter: spec.datacenter,
+        database_username: spec.username.unwrap_or("guepard".to_string()),
+        database_password,
         performance_profile_id,
-        node_id: args.node_id.clone(),
+        node_id: spec.node_id,
     };
 
-    let deployment = deploy::create_deployment(request, config).await?;
+    let mut deployment = deploy::create_deployment(request, config).await?;
+
+    // Keep the password out of the output entirely once it is stored locally
+    if args.secret_to_keyring {
+        let location = save_database_password(&deployment.id, &deployment.database_password)?;
+        deployment.database_password = REDACTED.to_string();
+        eprintln!("{} Database password stored in {}", "🔐".green(), location);
+    }
+
+    if args.wait {
+        let spec = WaitSpec::compute_running(&deployment.id, DEFAULT_WAIT_TIMEOUT);
+        wait::wait_for(&spec, config, output_format == OutputFormat::Table)
+            .await?
+            .check()?;
+    }
 
     // Try to get compute information for the real port
     let compute_data = match compute::list_compute(&deployment.id, config).await {
@@ -266,7 +454,11 @@ async fn create_deployment(
         println!("  {} {}", "Port:".yellow(), port_display);
     }
     if let Some(connection_string) = &deployment.connection_string {
-        println!("  {} {}", "Connection URI:".yellow(), connection_string);
+        println!(
+            "  {} {}",
+            "Connection URI:".yellow(),
+            redact_uri(connection_string)
+        );
     }
 
     // Show helpful connection information
@@ -283,15 +475,21 @@ async fn create_deployment(
     println!(
         "  {} {}",
         "Password:".yellow(),
-        deployment.database_password
+        secret(&deployment.database_password)
     );
+    if !secrets_shown() && !args.secret_to_keyring {
+        println!(
+            "  {}",
+            "Password hidden; add --show-secrets to reveal it".dimmed()
+        );
+    }
 
     println!();
     println!("{} Ready-to-use Connection URI:", "💡".green());
-    println!("{}", connection_uri.cyan().bold());
+    println!("{}", redact_uri(&connection_uri).cyan().bold());
     println!();
     println!("{} Connect with psql:", "📝".yellow());
-    println!("{} psql '{}'", "  $".dimmed(), connection_uri);
+    println!("{} psql '{}'", "  $".dimmed(), redact_uri(&connection_uri));
     println!();
     println!(
         "{} Connect with any PostgreSQL client using the URI above",
@@ -309,7 +507,7 @@ async fn create_deployment(
     Ok(())
 }
 
-fn generate_password() -> String {
+pub(crate) fn generate_password() -> String {
     // Generate a strong 13-character password with alphanumeric and special characters
     let chars: Vec<char> = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*"
         .chars()
@@ -352,6 +550,88 @@ async fn update_deployment(
     Ok(())
 }
 
+/// A reusable request for a deployment like this one. Secrets are left out; the
+/// password is read from `password_env` when the file is deployed.
+fn deployment_template(
+    deployment: &GetDeploymentResponse,
+    performance_profile: Option<String>,
+) -> ManifestDeployment {
+    ManifestDeployment {
+        name: deployment.repository_name.clone(),
+        provider: deployment.database_provider.clone(),
+        version: deployment.database_version.clone(),
+        region: deployment.region.clone(),
+        datacenter: deployment.datacenter.clone(),
+        deployment_type: deployment.deployment_type.clone(),
+        performance_profile,
+        username: Some(deployment.database_username.clone()),
+        password_env: Some(DEFAULT_PASSWORD_ENV.to_string()),
+        node_id: None,
+        branches: Vec::new(),
+        tenets: Vec::new(),
+    }
+}
+
+async fn export_template(
+    deployment_id: &str,
+    file: Option<&str>,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let deployment = deploy::get_deployment(deployment_id, config).await?;
+    if deployment.deployment_type == "SHADOW" {
+        return Err(DeployError::ApiError(
+            "Clones cannot be exported as templates; export their parent deployment instead"
+                .to_string(),
+        )
+        .into());
+    }
+
+    // The profile label is optional in a request, so a lookup failure only drops it
+    let performance_profile = match compute::list_compute(deployment_id, config).await {
+        Ok(compute) => performance::list_performance_profiles(config)
+            .await
+            .ok()
+            .and_then(|profiles| {
+                profiles
+                    .into_iter()
+                    .find(|p| p.id == compute.performance_profile_id)
+                    .map(|p| p.label_name)
+            }),
+        Err(_) => None,
+    };
+    let template = deployment_template(&deployment, performance_profile);
+
+    let as_json = match file {
+        Some(path) => path.ends_with(".json"),
+        None => output_format == OutputFormat::Json,
+    };
+    let content = if as_json {
+        serde_json::to_string_pretty(&template)? + "\n"
+    } else {
+        serde_yaml::to_string(&template)?
+    };
+
+    match file {
+        Some(path) => {
+            std::fs::write(path, content)?;
+            if output_format == OutputFormat::Table {
+                println!("{} Template saved to {}", "✅".green(), path.cyan());
+                println!(
+                    "{} Deploy it with: {}=... guepard deploy --from-file {}",
+                    "💡".yellow(),
+                    DEFAULT_PASSWORD_ENV,
+                    path
+                );
+            } else {
+                print_json(&serde_json::json!({"status": "exported", "file": path}));
+            }
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
 async fn get_deployment(
     deployment_id: &str,
     args: &DeployArgs,
@@ -596,7 +876,7 @@ async fn get_deployment(
         println!(
             "  {} {}",
             "Connection String:".yellow(),
-            compute.connection_string.cyan()
+            redact_uri(&compute.connection_string).cyan()
         );
     }
 
@@ -614,15 +894,21 @@ async fn get_deployment(
     println!(
         "  {} {}",
         "Password:".yellow(),
-        deployment.database_password
+        secret(&deployment.database_password)
     );
+    if !secrets_shown() {
+        println!(
+            "  {}",
+            "Password hidden; add --show-secrets to reveal it".dimmed()
+        );
+    }
 
     println!();
     println!("{} Ready-to-use Connection URI:", "💡".green());
-    println!("{}", connection_uri.cyan().bold());
+    println!("{}", redact_uri(&connection_uri).cyan().bold());
     println!();
     println!("{} Connect with psql:", "📝".yellow());
-    println!("{} psql '{}'", "  $".dimmed(), connection_uri);
+    println!("{} psql '{}'", "  $".dimmed(), redact_uri(&connection_uri));
     println!();
     println!(
         "{} Connect with any PostgreSQL client using the URI above",
@@ -633,18 +919,122 @@ async fn get_deployment(
     Ok(())
 }
 
+const UNMOUNT_POLL_INTERVAL_SECS: u64 = 3;
+const UNMOUNT_MAX_WAIT_SECS: u64 = 120;
+const PURGE_RETRY_ATTEMPTS: u32 = 3;
+const PURGE_RETRY_DELAY_SECS: u64 = 20;
+
+pub(crate) struct ComputeToStop {
+    pub id: String,
+    pub name: String,
+    pub role: &'static str,
+}
+
+/// Computes that must be stopped before a purge: the deployment's own, plus
+/// its shadows for F2 deployments.
+pub(crate) async fn computes_to_stop(
+    dep: &GetDeploymentResponse,
+    config: &Config,
+) -> Vec<ComputeToStop> {
+    let principal = ComputeToStop {
+        id: dep.id.clone(),
+        name: dep.name.clone(),
+        role: "principal",
+    };
+    if dep.deployment_type != "F2" {
+        return vec![principal];
+    }
+    match clone::list_clones(&dep.id, config).await {
+        Ok(shadows) => {
+            let mut v: Vec<ComputeToStop> = shadows
+                .into_iter()
+                .map(|s| ComputeToStop {
+                    id: s.id.clone(),
+                    name: s.name,
+                    role: "shadow",
+                })
+                .collect();
+            v.push(principal);
+            v
+        }
+        Err(_) => vec![principal],
+    }
+}
+
+/// Poll until the deployment's compute no longer reports "enabled" (volume
+/// unmounted). Returns false on timeout.
+async fn wait_for_unmount(deployment_id: &str, config: &Config, pb: Option<&ProgressBar>) -> bool {
+    let mut elapsed = 0u64;
+    while elapsed < UNMOUNT_MAX_WAIT_SECS {
+        tokio::time::sleep(Duration::from_secs(UNMOUNT_POLL_INTERVAL_SECS)).await;
+        elapsed += UNMOUNT_POLL_INTERVAL_SECS;
+        match compute::get_status(deployment_id, config).await {
+            Ok(res) if res.status.as_deref() != Some("enabled") => return true,
+            Err(ComputeError::NotFound(_)) => return true,
+            _ => {}
+        }
+        if let Some(pb) = pb {
+            pb.set_message(format!("Waiting for volume unmount… {}s", elapsed));
+        }
+    }
+    false
+}
+
+fn is_retryable_purge_error(message: &str) -> bool {
+    message.contains("502")
+        || message.contains("500")
+        || message.contains("Bad Gateway")
+        || message.contains("timed out")
+        || message.contains("Internal server error")
+}
+
+/// Purge without prompts or output: stop computes, wait for the volume to
+/// unmount, then purge with retries. Used by bulk purge, which reports a
+/// volume that never unmounts as this deployment's failure.
+pub(crate) async fn purge_unattended(
+    deployment_id: &str,
+    config: &Config,
+) -> Result<serde_json::Value> {
+    let dep = deploy::get_deployment(deployment_id, config).await?;
+    let mut stop_errors = Vec::new();
+    for c in computes_to_stop(&dep, config).await {
+        // A compute that is already stopped fails to stop; only the unmount decides
+        if let Err(e) = compute::stop_compute(&c.id, config).await {
+            stop_errors.push(format!("{} ({}): {}", c.name, c.role, e));
+        }
+    }
+    if !wait_for_unmount(deployment_id, config, None).await {
+        let mut message = format!(
+            "Volume did not unmount within {}s; deployment not purged",
+            UNMOUNT_MAX_WAIT_SECS
+        );
+        if !stop_errors.is_empty() {
+            message.push_str(&format!(". Stop failed for {}", stop_errors.join("; ")));
+        }
+        return Err(anyhow::anyhow!(message));
+    }
+
+    let mut attempt = 1;
+    loop {
+        match deploy::delete_deployment(deployment_id, config).await {
+            Ok(body) => return Ok(body),
+            Err(e)
+                if attempt < PURGE_RETRY_ATTEMPTS && is_retryable_purge_error(&e.to_string()) =>
+            {
+                attempt += 1;
+                tokio::time::sleep(Duration::from_secs(PURGE_RETRY_DELAY_SECS)).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
 async fn delete_deployment(
     deployment_id: &str,
     args: &DeployArgs,
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    struct ComputeToStop {
-        id: String,
-        name: String,
-        role: &'static str,
-    }
-
     let dep = match deploy::get_deployment(deployment_id, config).await {
         Ok(d) => d,
         Err(DeployError::NotFound) => {
@@ -671,37 +1061,7 @@ async fn delete_deployment(
         print!("\nResolving computes… ");
         io::stdout().flush()?;
     }
-    let computes_to_stop: Vec<ComputeToStop> = if dep.deployment_type == "F2" {
-        match clone::list_clones(deployment_id, config).await {
-            Ok(shadows) => {
-                let mut v: Vec<ComputeToStop> = shadows
-                    .into_iter()
-                    .map(|s| ComputeToStop {
-                        id: s.id.clone(),
-                        name: s.name,
-                        role: "shadow",
-                    })
-                    .collect();
-                v.push(ComputeToStop {
-                    id: deployment_id.to_string(),
-                    name: dep.name.clone(),
-                    role: "principal",
-                });
-                v
-            }
-            Err(_) => vec![ComputeToStop {
-                id: deployment_id.to_string(),
-                name: dep.name.clone(),
-                role: "principal",
-            }],
-        }
-    } else {
-        vec![ComputeToStop {
-            id: deployment_id.to_string(),
-            name: dep.name.clone(),
-            role: "principal",
-        }]
-    };
+    let computes_to_stop = computes_to_stop(&dep, config).await;
     if output_format == OutputFormat::Table {
         println!("{}", "✓".green());
     }
@@ -828,10 +1188,6 @@ async fn delete_deployment(
         }
     }
 
-    const POLL_INTERVAL_SECS: u64 = 3;
-    const MAX_WAIT_SECS: u64 = 120;
-    let mut elapsed = 0u64;
-    let mut stopped = false;
     let pb_wait = if output_format == OutputFormat::Table {
         let pb = ProgressBar::new_spinner();
         pb.set_style(
@@ -845,24 +1201,7 @@ async fn delete_deployment(
     } else {
         None
     };
-    while elapsed < MAX_WAIT_SECS {
-        tokio::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
-        elapsed += POLL_INTERVAL_SECS;
-        match compute::get_status(deployment_id, config).await {
-            Ok(res) if res.status.as_deref() != Some("enabled") => {
-                stopped = true;
-                break;
-            }
-            Err(e) if e.to_string().contains("404") => {
-                stopped = true;
-                break;
-            }
-            _ => {}
-        }
-        if let Some(ref pb) = pb_wait {
-            pb.set_message(format!("Waiting for volume unmount… {}s", elapsed));
-        }
-    }
+    let stopped = wait_for_unmount(deployment_id, config, pb_wait.as_ref()).await;
     if let Some(pb) = pb_wait {
         pb.finish_with_message(format!(
             "{} {}",
@@ -893,8 +1232,6 @@ async fn delete_deployment(
         None
     };
 
-    const PURGE_RETRY_ATTEMPTS: u32 = 3;
-    const PURGE_RETRY_DELAY_SECS: u64 = 20;
     let mut body = None;
     let mut last_err = None;
     for attempt in 1..=PURGE_RETRY_ATTEMPTS {
@@ -926,12 +1263,7 @@ async fn delete_deployment(
             }
             Err(e) => {
                 last_err = Some(e);
-                let s = last_err.as_ref().unwrap().to_string();
-                let retryable = s.contains("502")
-                    || s.contains("500")
-                    || s.contains("Bad Gateway")
-                    || s.contains("timed out")
-                    || s.contains("Internal server error");
+                let retryable = is_retryable_purge_error(&last_err.as_ref().unwrap().to_string());
                 if retryable && attempt < PURGE_RETRY_ATTEMPTS {
                     if output_format == OutputFormat::Table {
                         if let Some(ref pb) = pb_purge {
@@ -1044,210 +1376,31 @@ async fn delete_deployment(
 }
 
 async fn interactive_deploy(config: &Config) -> Result<()> {
-    println!("{} Welcome to Interactive Deployment! 🚀", "🐆".cyan());
-    println!(
-        "{} Let's create your database deployment step by step.",
-        "💡".yellow()
-    );
-    println!();
-
-    // Step 1: Database Provider
-    println!("{} Step 1: Choose Database Provider", "1️⃣".blue());
-    println!("Available options: PostgreSQL, MySQL, MongoDB");
-    print!("{} Database Provider [PostgreSQL]: ", "🔧".green());
-    io::stdout().flush()?;
-
-    let mut database_provider = String::new();
-    io::stdin().read_line(&mut database_provider)?;
-    let database_provider = database_provider.trim();
-    let database_provider = if database_provider.is_empty() {
-        "PostgreSQL"
-    } else {
-        database_provider
+    let answers = match wizard::run(config).await? {
+        Some(answers) => answers,
+        None => return Ok(()),
     };
 
-    // Step 2: Database Version
-    println!();
-    println!("{} Step 2: Choose Database Version", "2️⃣".blue());
-    let default_version = match database_provider {
-        "PostgreSQL" => "16",
-        "MySQL" => "8.0",
-        "MongoDB" => "7.0",
-        _ => "16",
-    };
-    print!("{} Database Version [{}]: ", "🔧".green(), default_version);
-    io::stdout().flush()?;
-
-    let mut database_version = String::new();
-    io::stdin().read_line(&mut database_version)?;
-    let database_version = database_version.trim();
-    let database_version = if database_version.is_empty() {
-        default_version
-    } else {
-        database_version
-    };
-
-    // Step 3: Region
-    println!();
-    println!("{} Step 3: Choose Region", "3️⃣".blue());
-    println!("Available options: us-west, us-east, eu-west, asia-pacific");
-    print!("{} Region [us-west]: ", "🌍".green());
-    io::stdout().flush()?;
-
-    let mut region = String::new();
-    io::stdin().read_line(&mut region)?;
-    let region = region.trim();
-    let region = if region.is_empty() { "us-west" } else { region };
-
-    // Step 4: Deployment Type
-    println!();
-    println!("{} Step 4: Choose Deployment Type", "4️⃣".blue());
-    println!("Available options: REPOSITORY, F2");
-    print!("{} Deployment Type [REPOSITORY]: ", "🏗️".green());
-    io::stdout().flush()?;
-
-    let mut instance_type = String::new();
-    io::stdin().read_line(&mut instance_type)?;
-    let instance_type = instance_type.trim();
-    let instance_type = if instance_type.is_empty() {
-        "REPOSITORY"
-    } else {
-        instance_type
-    };
-
-    // Step 5: Datacenter
-    println!();
-    println!("{} Step 5: Choose Datacenter", "5️⃣".blue());
-    println!("Available options: us-west-aws, us-east-aws, eu-west-aws");
-    print!("{} Datacenter [us-west-aws]: ", "🏢".green());
-    io::stdout().flush()?;
-
-    let mut datacenter = String::new();
-    io::stdin().read_line(&mut datacenter)?;
-    let datacenter = datacenter.trim();
-    let datacenter = if datacenter.is_empty() {
-        "us-west-aws"
-    } else {
-        datacenter
-    };
-
-    // Step 6: Repository Name
-    println!();
-    println!("{} Step 6: Repository Name", "6️⃣".blue());
-    print!("{} Repository Name [my-database]: ", "📁".green());
-    io::stdout().flush()?;
-
-    let mut repository_name = String::new();
-    io::stdin().read_line(&mut repository_name)?;
-    let repository_name = repository_name.trim();
-    let repository_name = if repository_name.is_empty() {
-        "my-database"
-    } else {
-        repository_name
-    };
-
-    // Step 7: Database Username (optional)
-    println!();
-    println!("{} Step 7: Database Username", "7️⃣".blue());
-    print!("{} Database Username [postgres]: ", "👤".green());
-    io::stdout().flush()?;
-
-    let mut user = String::new();
-    io::stdin().read_line(&mut user)?;
-    let user = user.trim();
-    let user = if user.is_empty() { "postgres" } else { user };
-
-    // Step 8: Database Password
-    println!();
-    println!("{} Step 8: Database Password", "8️⃣".blue());
-    print!(
-        "{} Database Password [press Enter to auto-generate]: ",
-        "🔐".green()
-    );
-    io::stdout().flush()?;
-
-    let mut database_password = String::new();
-    io::stdin().read_line(&mut database_password)?;
-    let database_password = database_password.trim();
-    let database_password = if database_password.is_empty() {
-        generate_password()
-    } else {
-        database_password.to_string()
-    };
-
-    // Step 9: Performance Profile
-    println!();
-    println!("{} Step 9: Performance Profile", "9️⃣".blue());
-    println!("Available options: gp.g1.xsmall, gp.g1.small, gp.g1.medium, gp.g1.large");
-    print!("{} Performance Profile [gp.g1.xsmall]: ", "⚡".green());
-    io::stdout().flush()?;
-
-    let mut performance_profile = String::new();
-    io::stdin().read_line(&mut performance_profile)?;
-    let performance_profile = performance_profile.trim();
-    let performance_profile = if performance_profile.is_empty() {
-        "gp.g1.xsmall"
-    } else {
-        performance_profile
-    };
-
-    // Summary
-    println!();
-    println!("{} Deployment Summary", "📋".blue());
-    println!("  {} {}", "Database Provider:".yellow(), database_provider);
-    println!("  {} {}", "Database Version:".yellow(), database_version);
-    println!("  {} {}", "Region:".yellow(), region);
-    println!("  {} {}", "Deployment Type:".yellow(), instance_type);
-    println!("  {} {}", "Datacenter:".yellow(), datacenter);
-    println!("  {} {}", "Repository Name:".yellow(), repository_name);
-    println!("  {} {}", "Username:".yellow(), user);
-    println!("  {} {}", "Password:".yellow(), database_password);
-    println!(
-        "  {} {}",
-        "Performance Profile:".yellow(),
-        performance_profile
-    );
-    println!();
-
-    // Confirmation
-    print!("{} Proceed with deployment? (Y/n): ", "❓".yellow());
-    io::stdout().flush()?;
-
-    let mut confirmation = String::new();
-    io::stdin().read_line(&mut confirmation)?;
-
-    let confirm_input = confirmation.trim().to_lowercase();
-    if !confirm_input.is_empty() && confirm_input.starts_with('n') {
-        println!("{} Deployment cancelled.", "ℹ️".blue());
-        return Ok(());
-    }
-
     // Create deployment
     println!();
     println!("{} Creating deployment...", "🚀".cyan());
 
-    // Get performance profile ID
-    let performance_profile_id = performance::get_performance_profile_by_label(
-        &performance_profile,
-        &database_provider,
-        &database_version,
-        config,
-    )
-    .await?;
-
-    let request = CreateDeploymentRequest {
-        repository_name: repository_name.to_string(),
-        database_provider: database_provider.to_string(),
-        database_version: database_version.to_string(),
-        deployment_type: instance_type.to_string(),
-        region: region.to_string(),
-        datacenter: datacenter.to_string(),
-        database_username: user.to_string(),
-        database_password: database_password.to_string(),
-        performance_profile_id,
-        node_id: None, // Interactive mode doesn't support node_id yet
+    let performance_profile_id = match &answers.performance_profile_id {
+        Some(id) => id.clone(),
+        None => {
+            let d = &answers.deployment;
+            performance::get_performance_profile_by_label(
+                d.performance_profile.as_deref().unwrap_or("gp.g1.xsmall"),
+                &d.provider,
+                &d.version,
+                config,
+            )
+            .await?
+        }
     };
 
+    let request = answers.to_request(performance_profile_id);
+    usage::ensure_quota(QuotaResource::Deployment, config).await?;
     let deployment = deploy::create_deployment(request, config).await?;
 
     println!("{} Deployment created successfully!", "✅".green());
@@ -1301,7 +1454,11 @@ async fn interactive_deploy(config: &Config) -> Result<()> {
         println!("  {} {}", "Port:".yellow(), port_display);
     }
     if let Some(connection_string) = &deployment.connection_string {
-        println!("  {} {}", "Connection URI:".yellow(), connection_string);
+        println!(
+            "  {} {}",
+            "Connection URI:".yellow(),
+            redact_uri(connection_string)
+        );
     }
 
     // Show helpful connection information
@@ -1318,8 +1475,14 @@ async fn interactive_deploy(config: &Config) -> Result<()> {
     println!(
         "  {} {}",
         "Password:".yellow(),
-        deployment.database_password
+        secret(&deployment.database_password)
     );
+    if !secrets_shown() {
+        println!(
+            "  {}",
+            "Password hidden; add --show-secrets to reveal it".dimmed()
+        );
+    }
 
     // Construct and show the connection URI
     let connection_uri = format!(
@@ -1332,10 +1495,10 @@ async fn interactive_deploy(config: &Config) -> Result<()> {
     );
     println!();
     println!("{} Ready-to-use Connection URI:", "💡".green());
-    println!("{}", connection_uri.cyan().bold());
+    println!("{}", redact_uri(&connection_uri).cyan().bold());
     println!();
     println!("{} Connect with psql:", "📝".yellow());
-    println!("{} psql '{}'", "  $".dimmed(), connection_uri);
+    println!("{} psql '{}'", "  $".dimmed(), redact_uri(&connection_uri));
     println!();
     println!(
         "{} Connect with any PostgreSQL client using the URI above",
@@ -1352,3 +1515,32 @@ async fn interactive_deploy(config: &Config) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_has_no_secrets_and_round_trips() {
+        let deployment: GetDeploymentResponse = serde_json::from_value(serde_json::json!({
+            "id": "dep-1", "name": "svc", "status": "CREATED", "customer_id": "c1",
+            "deployment_type": "REPOSITORY", "repository_name": "svc",
+            "fqdn": "svc.guepard.run", "database_provider": "PostgreSQL",
+            "database_version": "16", "database_username": "app",
+            "database_password": "s3cret", "created_by": "u1",
+            "created_date": "2025-01-01T00:00:00Z", "node_id": "node-9",
+            "region": "us-west", "datacenter": "aws"
+        }))
+        .unwrap();
+
+        let template = deployment_template(&deployment, Some("gp.g1.small".to_string()));
+        let yaml = serde_yaml::to_string(&template).unwrap();
+        assert!(!yaml.contains("s3cret"));
+        assert!(!yaml.contains("node-9"));
+
+        let parsed = ManifestDeployment::parse_request(&yaml).unwrap();
+        assert_eq!(parsed.provider, "PostgreSQL");
+        assert_eq!(parsed.performance_profile.as_deref(), Some("gp.g1.small"));
+        assert_eq!(parsed.password_env.as_deref(), Some(DEFAULT_PASSWORD_ENV));
+    }
+}
@@ -0,0 +1,497 @@
+use crate::application::auth::jwt_claims;
+use crate::application::commands::connection::resolve_params;
+use crate::application::output::{print_json, print_table_or_json, OutputFormat};
+use crate::application::services::deploy;
+use crate::config::config::{
+    load_config_data, load_jwt_token, resolve_deployment_id, token_storage, Config,
+};
+use crate::domain::errors::deploy_error::DeployError;
+use crate::structure::DoctorArgs;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt;
+use std::time::{Duration, Instant};
+use tabled::Tabled;
+
+const NETWORK_TIMEOUT_SECS: u64 = 10;
+const SLOW_API_MS: u128 = 2000;
+const TOKEN_EXPIRY_WARN_HOURS: i64 = 24;
+const CLOCK_SKEW_WARN_SECS: i64 = 30;
+const CLOCK_SKEW_FAIL_SECS: i64 = 300;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckStatus::Pass => write!(f, "{}", "✅ pass".green()),
+            CheckStatus::Warn => write!(f, "{}", "⚠️ warn".yellow()),
+            CheckStatus::Fail => write!(f, "{}", "❌ fail".red()),
+        }
+    }
+}
+
+#[derive(Tabled, Serialize, Debug)]
+struct Check {
+    #[tabled(rename = "Check")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: CheckStatus,
+    #[tabled(rename = "Details")]
+    detail: String,
+    #[tabled(rename = "Fix")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    remediation: String,
+}
+
+impl Check {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, detail, "")
+    }
+
+    fn new(
+        name: &str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        remediation: impl Into<String>,
+    ) -> Self {
+        Check {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+            remediation: remediation.into(),
+        }
+    }
+}
+
+fn check_config_file() -> Check {
+    let exists = dirs::home_dir()
+        .map(|home| home.join(".guepard/config.json").exists())
+        .unwrap_or(false);
+    match load_config_data() {
+        Ok(data) if exists => Check::pass("Config file", format!("API URL {}", data.api_url)),
+        Ok(_) => Check::pass("Config file", "Not present; using defaults"),
+        Err(e) => Check::new(
+            "Config file",
+            CheckStatus::Fail,
+            e.to_string(),
+            "Fix or delete ~/.guepard/config.json, then run 'guepard config --api-url <url>'",
+        ),
+    }
+}
+
+/// Permission bits that expose `~/.guepard` entries: any group/other access on
+/// files (tokens, secrets), group/other write on directories.
+fn insecure_bits(mode: u32, is_dir: bool) -> u32 {
+    if is_dir {
+        mode & 0o022
+    } else {
+        mode & 0o077
+    }
+}
+
+#[cfg(unix)]
+fn check_permissions() -> Option<Check> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = dirs::home_dir()?.join(".guepard");
+    if !dir.exists() {
+        return None;
+    }
+
+    let mut insecure = Vec::new();
+    let mut visit = vec![dir.clone()];
+    while let Some(path) = visit.pop() {
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if insecure_bits(metadata.permissions().mode(), metadata.is_dir()) != 0 {
+            insecure.push(path.clone());
+        }
+        if metadata.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                visit.extend(entries.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        }
+    }
+
+    Some(if insecure.is_empty() {
+        Check::pass("File permissions", "Files in ~/.guepard are private (0600)")
+    } else {
+        insecure.sort();
+        let paths: Vec<String> = insecure.iter().map(|p| p.display().to_string()).collect();
+        Check::new(
+            "File permissions",
+            CheckStatus::Warn,
+            format!("Accessible to other users: {}", paths.join(", ")),
+            format!("chmod -R go-rwx {}", dir.display()),
+        )
+    })
+}
+
+#[cfg(not(unix))]
+fn check_permissions() -> Option<Check> {
+    None
+}
+
+fn check_token_storage() -> Check {
+    match token_storage() {
+        Ok(storage) => Check::pass("Token storage", storage),
+        Err(e) => Check::new(
+            "Token storage",
+            CheckStatus::Fail,
+            e.to_string(),
+            "Unlock or install an OS keyring (e.g. gnome-keyring), or use a build without keyring support",
+        ),
+    }
+}
+
+fn check_token_expiry(claims: &serde_json::Value, now: DateTime<Utc>) -> Check {
+    let Some(exp) = claims.get("exp").and_then(|v| v.as_i64()) else {
+        return Check::pass("Session token", "Present (no expiry claim)");
+    };
+    let Some(expires) = DateTime::from_timestamp(exp, 0) else {
+        return Check::new(
+            "Session token",
+            CheckStatus::Warn,
+            format!("Unreadable expiry claim {}", exp),
+            "Run 'guepard login' to get a fresh token",
+        );
+    };
+
+    let remaining = expires.signed_duration_since(now);
+    if remaining.num_seconds() <= 0 {
+        Check::new(
+            "Session token",
+            CheckStatus::Fail,
+            format!("Expired at {}", expires.to_rfc3339()),
+            "Run 'guepard login'",
+        )
+    } else if remaining.num_hours() < TOKEN_EXPIRY_WARN_HOURS {
+        Check::new(
+            "Session token",
+            CheckStatus::Warn,
+            format!(
+                "Expires in {} min ({})",
+                remaining.num_minutes(),
+                expires.to_rfc3339()
+            ),
+            "Run 'guepard login' before long-running work",
+        )
+    } else {
+        Check::pass(
+            "Session token",
+            format!("Valid until {}", expires.to_rfc3339()),
+        )
+    }
+}
+
+/// The session token check, and whether a token is stored at all.
+fn check_token() -> (Check, bool) {
+    match load_jwt_token() {
+        Ok(token) => {
+            let check = match jwt_claims(&token) {
+                Ok(claims) => check_token_expiry(&claims, Utc::now()),
+                Err(e) => Check::new(
+                    "Session token",
+                    CheckStatus::Warn,
+                    format!("Present but not a readable JWT: {}", e),
+                    "Run 'guepard login' if API calls are rejected",
+                ),
+            };
+            (check, true)
+        }
+        Err(_) => (
+            Check::new(
+                "Session token",
+                CheckStatus::Fail,
+                "Not logged in",
+                "Run 'guepard login'",
+            ),
+            false,
+        ),
+    }
+}
+
+/// Compare the server's `Date` header with the local clock taken halfway
+/// through the request.
+fn check_clock_skew(server_date: &str, local: DateTime<Utc>) -> Check {
+    let Ok(server) = DateTime::parse_from_rfc2822(server_date) else {
+        return Check::new(
+            "Clock skew",
+            CheckStatus::Warn,
+            format!("Unreadable server Date header '{}'", server_date),
+            "",
+        );
+    };
+    let skew = local.signed_duration_since(server).num_seconds();
+    let detail = format!(
+        "Local clock is {}s {} the server",
+        skew.abs(),
+        if skew >= 0 { "ahead of" } else { "behind" }
+    );
+    let remediation = "Enable time sync (NTP), e.g. 'timedatectl set-ntp true'";
+    match skew.abs() {
+        s if s > CLOCK_SKEW_FAIL_SECS => {
+            Check::new("Clock skew", CheckStatus::Fail, detail, remediation)
+        }
+        s if s > CLOCK_SKEW_WARN_SECS => {
+            Check::new("Clock skew", CheckStatus::Warn, detail, remediation)
+        }
+        _ => Check::pass("Clock skew", detail),
+    }
+}
+
+/// API reachability and latency, plus the clock skew check when the response
+/// carries a Date header.
+async fn check_api(config: &Config) -> Vec<Check> {
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(NETWORK_TIMEOUT_SECS))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            return vec![Check::new(
+                "API reachability",
+                CheckStatus::Fail,
+                e.to_string(),
+                "",
+            )]
+        }
+    };
+
+    let started = Instant::now();
+    let sent_at = Utc::now();
+    let response = match client.get(&config.api_url).send().await {
+        Ok(response) => response,
+        Err(e) => {
+            return vec![Check::new(
+                "API reachability",
+                CheckStatus::Fail,
+                format!("{}: {}", config.api_url, e),
+                "Check your network, proxy (HTTPS_PROXY) and 'guepard config --api-url'",
+            )]
+        }
+    };
+    let elapsed = started.elapsed();
+    let midpoint = sent_at + chrono::Duration::milliseconds(elapsed.as_millis() as i64 / 2);
+
+    let detail = format!(
+        "{} answered HTTP {} in {} ms",
+        config.api_url,
+        response.status().as_u16(),
+        elapsed.as_millis()
+    );
+    let mut checks = vec![if elapsed.as_millis() > SLOW_API_MS {
+        Check::new(
+            "API reachability",
+            CheckStatus::Warn,
+            detail,
+            "Slow responses; check your network or proxy",
+        )
+    } else {
+        Check::pass("API reachability", detail)
+    }];
+
+    if let Some(date) = response
+        .headers()
+        .get(reqwest::header::DATE)
+        .and_then(|v| v.to_str().ok())
+    {
+        checks.push(check_clock_skew(date, midpoint));
+    }
+    checks
+}
+
+async fn check_api_auth(config: &Config) -> Check {
+    match deploy::list_deployments(config).await {
+        Ok(deployments) => Check::pass(
+            "API authentication",
+            format!(
+                "Token accepted; {} deployment(s) visible",
+                deployments.len()
+            ),
+        ),
+        Err(DeployError::SessionError(e)) => Check::new(
+            "API authentication",
+            CheckStatus::Fail,
+            e,
+            "Run 'guepard login'",
+        ),
+        Err(e) => Check::new(
+            "API authentication",
+            CheckStatus::Fail,
+            e.to_string(),
+            "Run 'guepard login'; if it persists, check 'guepard config --api-url'",
+        ),
+    }
+}
+
+/// DNS and TCP checks for a deployment's endpoint.
+async fn check_deployment(deployment_id: &str, config: &Config) -> Vec<Check> {
+    let params = match resolve_params(deployment_id, None, None, config).await {
+        Ok(params) => params,
+        Err(e) => {
+            return vec![Check::new(
+                "Deployment",
+                CheckStatus::Fail,
+                format!("{}: {}", deployment_id, e),
+                "Check the ID with 'guepard list deployments'",
+            )]
+        }
+    };
+
+    let timeout = Duration::from_secs(NETWORK_TIMEOUT_SECS);
+    let dns_name = format!("DNS {}", params.host);
+    let addresses = match tokio::time::timeout(
+        timeout,
+        tokio::net::lookup_host((params.host.as_str(), params.port)),
+    )
+    .await
+    {
+        Ok(Ok(addresses)) => addresses.collect::<Vec<_>>(),
+        Ok(Err(e)) => {
+            return vec![Check::new(
+                &dns_name,
+                CheckStatus::Fail,
+                e.to_string(),
+                "The deployment may still be provisioning; check 'guepard deploy -x <id>' and your DNS resolver",
+            )]
+        }
+        Err(_) => {
+            return vec![Check::new(
+                &dns_name,
+                CheckStatus::Fail,
+                "Lookup timed out",
+                "Check your DNS resolver",
+            )]
+        }
+    };
+    let ips: Vec<String> = addresses.iter().map(|a| a.ip().to_string()).collect();
+    let mut checks = vec![Check::pass(&dns_name, ips.join(", "))];
+
+    let tcp_name = format!("TCP {}:{}", params.host, params.port);
+    let started = Instant::now();
+    let remediation = format!(
+        "Start the compute with 'guepard compute start -x {}', or allow outbound port {} in your firewall",
+        deployment_id, params.port
+    );
+    checks.push(
+        match tokio::time::timeout(timeout, tokio::net::TcpStream::connect(&addresses[..])).await {
+            Ok(Ok(_)) => Check::pass(
+                &tcp_name,
+                format!("Connected in {} ms", started.elapsed().as_millis()),
+            ),
+            Ok(Err(e)) => Check::new(&tcp_name, CheckStatus::Fail, e.to_string(), remediation),
+            Err(_) => Check::new(
+                &tcp_name,
+                CheckStatus::Fail,
+                "Connection timed out",
+                remediation,
+            ),
+        },
+    );
+    checks
+}
+
+pub async fn doctor(args: &DoctorArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let mut checks = vec![check_config_file()];
+    checks.extend(check_permissions());
+    checks.push(check_token_storage());
+    let (token, logged_in) = check_token();
+    checks.push(token);
+    checks.extend(check_api(config).await);
+    if logged_in {
+        checks.push(check_api_auth(config).await);
+    }
+
+    let deployment_id = match &args.deployment_id {
+        Some(id) => Some(id.clone()),
+        None => resolve_deployment_id(None).ok(),
+    };
+    if let Some(deployment_id) = &deployment_id {
+        checks.extend(check_deployment(deployment_id, config).await);
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let (passed, warnings, failed) = (
+        count(CheckStatus::Pass),
+        count(CheckStatus::Warn),
+        count(CheckStatus::Fail),
+    );
+
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "checks": checks,
+            "summary": {"pass": passed, "warn": warnings, "fail": failed},
+        }));
+    } else {
+        print_table_or_json(checks, output_format);
+        println!(
+            "{} passed, {} warning(s), {} failed",
+            passed.to_string().green(),
+            warnings.to_string().yellow(),
+            failed.to_string().red()
+        );
+        if deployment_id.is_none() {
+            println!(
+                "{} Add -x <deployment_id> to also check DNS and TCP reachability of a deployment",
+                "💡".yellow()
+            );
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} check(s) failed", failed));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_expiry_thresholds() {
+        let now = DateTime::parse_from_rfc3339("2025-06-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let check = |exp: i64| check_token_expiry(&serde_json::json!({ "exp": exp }), now).status;
+
+        assert_eq!(check(now.timestamp() - 1), CheckStatus::Fail);
+        assert_eq!(check(now.timestamp() + 3600), CheckStatus::Warn);
+        assert_eq!(check(now.timestamp() + 7 * 24 * 3600), CheckStatus::Pass);
+        assert_eq!(
+            check_token_expiry(&serde_json::json!({}), now).status,
+            CheckStatus::Pass
+        );
+    }
+
+    #[test]
+    fn test_clock_skew_and_permissions() {
+        let local = DateTime::parse_from_rfc3339("2025-06-01T12:00:10Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let check = check_clock_skew("Sun, 01 Jun 2025 12:00:00 GMT", local);
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert_eq!(check.detail, "Local clock is 10s ahead of the server");
+        assert_eq!(
+            check_clock_skew("Sun, 01 Jun 2025 11:50:00 GMT", local).status,
+            CheckStatus::Fail
+        );
+        assert_eq!(check_clock_skew("garbage", local).status, CheckStatus::Warn);
+
+        assert_eq!(insecure_bits(0o100600, false), 0);
+        assert_eq!(insecure_bits(0o040755, true), 0);
+        assert_ne!(insecure_bits(0o100644, false), 0);
+        assert_ne!(insecure_bits(0o040777, true), 0);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::application::commands::connection::{
+    resolve_params, shell_quote, ConnectionParams, Engine,
+};
+use crate::application::output::{print_json_with_secrets, OutputFormat};
+use crate::config::config::{resolve_deployment_id, Config};
+use crate::structure::EnvArgs;
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Serialize, Debug, Default, PartialEq)]
+struct EnvChanges {
+    added: Vec<String>,
+    updated: Vec<String>,
+    unchanged: Vec<String>,
+}
+
+/// Key/value pairs to write. PostgreSQL defaults to libpq's PG* names so psql
+/// and most drivers pick them up; other engines and `--prefix` use
+/// `<PREFIX>HOST`, `<PREFIX>PORT`, ...
+fn env_entries(params: &ConnectionParams, prefix: Option<&str>) -> Vec<(String, String)> {
+    let names: [&str; 6] = match (prefix, params.engine) {
+        (None, Engine::PostgreSQL) => [
+            "DATABASE_URL",
+            "PGHOST",
+            "PGPORT",
+            "PGDATABASE",
+            "PGUSER",
+            "PGPASSWORD",
+        ],
+        (None, _) => ["DATABASE_URL", "HOST", "PORT", "NAME", "USER", "PASSWORD"],
+        (Some(_), _) => ["URL", "HOST", "PORT", "NAME", "USER", "PASSWORD"],
+    };
+    let prefix = match (prefix, params.engine) {
+        (Some(prefix), _) => prefix,
+        (None, Engine::PostgreSQL) => "",
+        (None, _) => "DB_",
+    };
+    let key = |name: &str| {
+        if name == "DATABASE_URL" {
+            name.to_string()
+        } else {
+            format!("{}{}", prefix, name)
+        }
+    };
+
+    let mut entries = vec![
+        (key(names[0]), params.uri()),
+        (key(names[1]), params.host.clone()),
+        (key(names[2]), params.port.to_string()),
+        (key(names[3]), params.database.clone()),
+        (key(names[4]), params.username.clone()),
+        (key(names[5]), params.password.clone()),
+    ];
+    if let Some(mode) = &params.sslmode {
+        let name = if prefix.is_empty() {
+            "PGSSLMODE"
+        } else {
+            "SSLMODE"
+        };
+        entries.push((key(name), mode.clone()));
+    }
+    entries
+}
+
+/// Quote a value so dotenvy reads it back unchanged (no `$` expansion).
+fn dotenv_value(value: &str) -> String {
+    if value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:@".contains(c))
+    {
+        value.to_string()
+    } else if !value.contains('\'') {
+        format!("'{}'", value)
+    } else {
+        let escaped = value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('$', "\\$");
+        format!("\"{}\"", escaped)
+    }
+}
+
+/// The key assigned on a dotenv line, if any (`KEY=...` or `export KEY=...`).
+fn line_key(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') {
+        return None;
+    }
+    let trimmed = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+    let (key, _) = trimmed.split_once('=')?;
+    let key = key.trim();
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+/// Update the given keys in place and append missing ones, keeping every other
+/// line (including comments and blank lines) untouched.
+fn upsert_dotenv(
+    content: &str,
+    entries: &[(String, String)],
+    existing: &HashMap<String, String>,
+) -> (String, EnvChanges) {
+    let mut changes = EnvChanges::default();
+    let mut written: Vec<&str> = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        match line_key(line).and_then(|key| entries.iter().find(|(k, _)| k == key)) {
+            // Later duplicates of a key we already wrote are dropped
+            Some((key, _)) if written.contains(&key.as_str()) => continue,
+            Some((key, value)) => {
+                let export = if line.trim_start().starts_with("export ") {
+                    "export "
+                } else {
+                    ""
+                };
+                lines.push(format!("{}{}={}", export, key, dotenv_value(value)));
+                written.push(key);
+                if existing.get(key) == Some(value) {
+                    changes.unchanged.push(key.clone());
+                } else {
+                    changes.updated.push(key.clone());
+                }
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    let missing: Vec<&(String, String)> = entries
+        .iter()
+        .filter(|(k, _)| !written.contains(&k.as_str()))
+        .collect();
+    if !missing.is_empty() {
+        if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push("# Database connection (guepard env)".to_string());
+        for (key, value) in missing {
+            lines.push(format!("{}={}", key, dotenv_value(value)));
+            changes.added.push(key.clone());
+        }
+    }
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    (output, changes)
+}
+
+fn write_dotenv(path: &Path, entries: &[(String, String)]) -> Result<EnvChanges> {
+    let content = if path.exists() {
+        fs::read_to_string(path)?
+    } else {
+        String::new()
+    };
+    let existing: HashMap<String, String> = dotenvy::from_read_iter(content.as_bytes())
+        .filter_map(|item| item.ok())
+        .collect();
+
+    let (updated, changes) = upsert_dotenv(&content, entries, &existing);
+
+    // Make sure what we wrote reads back as intended before replacing the file
+    let reparsed: HashMap<String, String> = dotenvy::from_read_iter(updated.as_bytes())
+        .collect::<Result<_, _>>()
+        .map_err(|e| anyhow::anyhow!("Refusing to write {}: {}", path.display(), e))?;
+    for (key, value) in entries {
+        if reparsed.get(key) != Some(value) {
+            return Err(anyhow::anyhow!(
+                "Refusing to write {}: {} would not round-trip",
+                path.display(),
+                key
+            ));
+        }
+    }
+
+    let tmp = path.with_extension("guepard.tmp");
+    fs::write(&tmp, updated)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path)
+            .map(|m| m.permissions().mode())
+            .unwrap_or(0o600);
+        fs::set_permissions(&tmp, fs::Permissions::from_mode(mode))?;
+    }
+    fs::rename(&tmp, path)?;
+    Ok(changes)
+}
+
+pub async fn env(args: &EnvArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let deployment_id = resolve_deployment_id(args.deployment_id.as_deref())?;
+    let mut params = resolve_params(&deployment_id, None, args.clone.as_deref(), config).await?;
+    params.sslmode = args.sslmode.clone();
+    let entries = env_entries(&params, args.prefix.as_deref());
+
+    if let Some(path) = &args.write {
+        let changes = write_dotenv(Path::new(path), &entries)?;
+        if output_format == OutputFormat::Json {
+            print_json_with_secrets(&serde_json::json!({
+                "file": path,
+                "changes": changes,
+            }));
+        } else {
+            println!(
+                "{} Updated {} ({} added, {} updated, {} unchanged)",
+                "✅".green(),
+                path.cyan(),
+                changes.added.len(),
+                changes.updated.len(),
+                changes.unchanged.len()
+            );
+        }
+        return Ok(());
+    }
+
+    if output_format == OutputFormat::Json {
+        let map: serde_json::Map<String, serde_json::Value> = entries
+            .into_iter()
+            .map(|(k, v)| (k, serde_json::Value::String(v)))
+            .collect();
+        print_json_with_secrets(&map);
+        return Ok(());
+    }
+
+    for (key, value) in &entries {
+        if args.shell {
+            println!("export {}={}", key, shell_quote(value));
+        } else {
+            println!("{}={}", key, dotenv_value(value));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::connection::test_params;
+
+    #[test]
+    fn test_env_entries_default_and_prefixed() {
+        let entries = env_entries(&test_params("PostgreSQL", Some(5432), "s3cr$t 'x'"), None);
+        let keys: Vec<&str> = entries.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "DATABASE_URL",
+                "PGHOST",
+                "PGPORT",
+                "PGDATABASE",
+                "PGUSER",
+                "PGPASSWORD"
+            ]
+        );
+
+        let entries = env_entries(
+            &test_params("PostgreSQL", Some(5432), "s3cr$t 'x'"),
+            Some("DATABASE_"),
+        );
+        let keys: Vec<&str> = entries.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "DATABASE_URL",
+                "DATABASE_HOST",
+                "DATABASE_PORT",
+                "DATABASE_NAME",
+                "DATABASE_USER",
+                "DATABASE_PASSWORD"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_upsert_preserves_other_lines() {
+        let content = "# app settings\nAPP_ENV=dev\nexport PGHOST=old.host\n\nPGPORT=5432\n";
+        let existing: HashMap<String, String> = dotenvy::from_read_iter(content.as_bytes())
+            .map(|i| i.unwrap())
+            .collect();
+        let entries = env_entries(&test_params("PostgreSQL", Some(5432), "s3cr$t 'x'"), None);
+        let (updated, changes) = upsert_dotenv(content, &entries, &existing);
+
+        assert!(updated.starts_with("# app settings\nAPP_ENV=dev\nexport PGHOST=db.guepard.run\n"));
+        assert_eq!(changes.updated, vec!["PGHOST"]);
+        assert_eq!(changes.unchanged, vec!["PGPORT"]);
+        assert_eq!(changes.added.len(), 4);
+
+        let reparsed: HashMap<String, String> = dotenvy::from_read_iter(updated.as_bytes())
+            .map(|i| i.unwrap())
+            .collect();
+        assert_eq!(reparsed["PGPASSWORD"], "s3cr$t 'x'");
+        assert_eq!(reparsed["APP_ENV"], "dev");
+    }
+
+    #[test]
+    fn test_line_key() {
+        assert_eq!(line_key("PGHOST=x"), Some("PGHOST"));
+        assert_eq!(line_key("export PGHOST = x"), Some("PGHOST"));
+        assert_eq!(line_key("# PGHOST=x"), None);
+        assert_eq!(line_key("not a pair"), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::application::dto::deploy::ListDeploymentsResponse;
 use crate::application::services::{branch, clone, commit, deploy};
 use crate::config::config::Config;
 use crate::structure::ListArgs;
@@ -32,6 +33,17 @@ const PERFORMANCE_COLUMNS: &[&str] = &[
     "is_default",
 ];
 
+const IMAGE_COLUMNS: &[&str] = &[
+    "id",
+    "image_name",
+    "database_provider",
+    "database_version",
+    "default_port",
+    "support_status",
+    "is_default",
+    "is_active",
+];
+
 const BRANCH_COLUMNS: &[&str] = &[
     "id",
     "branch_name",
@@ -51,6 +63,17 @@ const COMMIT_COLUMNS: &[&str] = &[
     "type",
 ];
 
+const ALL_CLONE_COLUMNS: &[&str] = &[
+    "id",
+    "name",
+    "status",
+    "deployment_parent",
+    "snapshot_id",
+    "branch_id",
+    "description",
+    "created",
+];
+
 const CLONE_COLUMNS: &[&str] = &[
     "id",
     "name",
@@ -65,25 +88,28 @@ const CLONE_COLUMNS: &[&str] = &[
     "connection",
 ];
 
-use crate::application::output::{print_json, OutputFormat};
+use crate::application::output::{print_json, redact_field, OutputFormat};
 
 pub async fn list(args: &ListArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
     match args.resource.as_str() {
         "deployments" => list_deployments(args, config, output_format).await,
         "branches" => list_branches(args, config, output_format).await,
         "commits" => list_commits(args, config, output_format).await,
+        "clones" if args.all => list_all_clones(args, config, output_format).await,
         "clones" => list_clones(args, config, output_format).await,
+        "f2" => list_f2(args, config, output_format).await,
         "performance" => list_performance(args, config, output_format).await,
+        "images" => list_images(args, config, output_format).await,
         _ => {
             if output_format == OutputFormat::Table {
                 println!("{} Unknown resource: {}", "❌".red(), args.resource);
                 println!(
-                    "Available resources: deployments, branches, commits, clones, performance"
+                    "Available resources: deployments, f2, branches, commits, clones, performance, images"
                 );
             } else {
                 print_json(&serde_json::json!({
                     "error": format!("Unknown resource: {}", args.resource),
-                    "available_resources": ["deployments", "branches", "commits", "clones", "performance"]
+                    "available_resources": ["deployments", "f2", "branches", "commits", "clones", "performance", "images"]
                 }));
             }
             Ok(())
@@ -119,6 +145,14 @@ fn parse_columns(columns_str: &Option<String>, available_columns: &[&str]) -> Ve
                 "database_version".to_string(),
                 "is_default".to_string(),
             ],
+            IMAGE_COLUMNS => vec![
+                "id".to_string(),
+                "image_name".to_string(),
+                "database_provider".to_string(),
+                "database_version".to_string(),
+                "support_status".to_string(),
+                "is_default".to_string(),
+            ],
             BRANCH_COLUMNS => vec![
                 "id".to_string(),
                 "branch_name".to_string(),
@@ -134,6 +168,14 @@ fn parse_columns(columns_str: &Option<String>, available_columns: &[&str]) -> Ve
                 "dataset_id".to_string(),
                 "parent_id".to_string(),
             ],
+            ALL_CLONE_COLUMNS => vec![
+                "id".to_string(),
+                "name".to_string(),
+                "status".to_string(),
+                "deployment_parent".to_string(),
+                "snapshot_id".to_string(),
+                "created".to_string(),
+            ],
             CLONE_COLUMNS => vec![
                 "id".to_string(),
                 "name".to_string(),
@@ -149,7 +191,7 @@ fn parse_columns(columns_str: &Option<String>, available_columns: &[&str]) -> Ve
 
 fn show_available_columns(resource: &str) {
     match resource {
-        "deployments" => {
+        "deployments" | "f2" => {
             println!("{} Available columns for deployments:", "ℹ️".blue());
             println!("{}", DEPLOYMENT_COLUMNS.join(", "));
         }
@@ -157,6 +199,10 @@ fn show_available_columns(resource: &str) {
             println!("{} Available columns for performance:", "ℹ️".blue());
             println!("{}", PERFORMANCE_COLUMNS.join(", "));
         }
+        "images" => {
+            println!("{} Available columns for images:", "ℹ️".blue());
+            println!("{}", IMAGE_COLUMNS.join(", "));
+        }
         "branches" => {
             println!("{} Available columns for branches:", "ℹ️".blue());
             println!("{}", BRANCH_COLUMNS.join(", "));
@@ -169,6 +215,10 @@ fn show_available_columns(resource: &str) {
             println!("{} Available columns for clones:", "ℹ️".blue());
             println!("{}", CLONE_COLUMNS.join(", "));
         }
+        "all-clones" => {
+            println!("{} Available columns for clones --all:", "ℹ️".blue());
+            println!("{}", ALL_CLONE_COLUMNS.join(", "));
+        }
         _ => {}
     }
 }
@@ -253,6 +303,157 @@ async fn list_performance(
     Ok(())
 }
 
+async fn list_images(args: &ListArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let mut images = crate::application::services::image::list_image_providers(config).await?;
+
+    if images.is_empty() {
+        if output_format == OutputFormat::Json {
+            print_json(&serde_json::json!([]));
+        } else {
+            println!("{} No image providers found", "ℹ️".blue());
+        }
+        return Ok(());
+    }
+
+    // Apply limit if specified
+    let total_count = images.len();
+    if let Some(limit) = args.limit {
+        images.truncate(limit);
+    }
+
+    let selected_columns = parse_columns(&args.columns, IMAGE_COLUMNS);
+
+    if selected_columns.is_empty() {
+        println!(
+            "{} No valid columns selected. Available columns:",
+            "❌".red()
+        );
+        show_available_columns("images");
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for image in images {
+        let mut row_data = std::collections::HashMap::new();
+
+        for col in &selected_columns {
+            let value = match col.as_str() {
+                "id" => image.id.clone(),
+                "image_name" => image.catalog.image_name.clone(),
+                "database_provider" => image.catalog.database_provider.clone(),
+                "database_version" => image.catalog.database_version.clone(),
+                "default_port" => image
+                    .catalog
+                    .default_port
+                    .map(|p| p.to_string())
+                    .unwrap_or_default(),
+                "support_status" => image.catalog.support_status.clone().unwrap_or_default(),
+                "is_default" => image.is_default.to_string(),
+                "is_active" => image.is_active.to_string(),
+                _ => "".to_string(),
+            };
+            row_data.insert(col.clone(), value);
+        }
+        rows.push(row_data);
+    }
+
+    if output_format == OutputFormat::Json {
+        print_json(&rows);
+        return Ok(());
+    }
+
+    println!(
+        "{} Found {} image providers{}",
+        "✅".green(),
+        total_count,
+        match args.limit {
+            Some(limit) if limit < total_count => format!(" (showing first {})", limit),
+            _ => String::new(),
+        }
+    );
+
+    display_dynamic_table(rows, &selected_columns);
+    Ok(())
+}
+
+async fn list_all_clones(
+    args: &ListArgs,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let mut shadows = clone::list_all_clones(config).await?;
+
+    if shadows.is_empty() {
+        if output_format == OutputFormat::Json {
+            print_json(&serde_json::json!([]));
+        } else {
+            println!("{} No clones found", "ℹ️".blue());
+        }
+        return Ok(());
+    }
+
+    // Apply limit if specified
+    let total_count = shadows.len();
+    if let Some(limit) = args.limit {
+        shadows.truncate(limit);
+    }
+
+    let selected_columns = parse_columns(&args.columns, ALL_CLONE_COLUMNS);
+
+    if selected_columns.is_empty() {
+        println!(
+            "{} No valid columns selected. Available columns:",
+            "❌".red()
+        );
+        show_available_columns("all-clones");
+        return Ok(());
+    }
+
+    let mut rows = Vec::new();
+    for shadow in &shadows {
+        let mut row_data = std::collections::HashMap::new();
+
+        for col in &selected_columns {
+            let value = match col.as_str() {
+                "id" => shadow.id.clone(),
+                "name" => shadow.name.clone().unwrap_or_default(),
+                "status" => shadow.status.clone().unwrap_or_default(),
+                "deployment_parent" => shadow.deployment_parent.clone().unwrap_or_default(),
+                "snapshot_id" => shadow.snapshot_id.clone().unwrap_or_default(),
+                "branch_id" => shadow.branch_id.clone().unwrap_or_default(),
+                "description" => shadow.description.clone().unwrap_or_default(),
+                "created" => shadow.created.clone().unwrap_or_default(),
+                _ => "".to_string(),
+            };
+            row_data.insert(col.clone(), value);
+        }
+        rows.push(row_data);
+    }
+
+    if output_format == OutputFormat::Json {
+        print_json(&rows);
+        return Ok(());
+    }
+
+    let parents: HashSet<&str> = shadows
+        .iter()
+        .filter_map(|s| s.deployment_parent.as_deref())
+        .collect();
+    println!(
+        "{} Found {} clones across {} deployments{}",
+        "✅".green(),
+        total_count,
+        parents.len(),
+        match args.limit {
+            Some(limit) if limit < total_count => format!(" (showing first {})", limit),
+            _ => String::new(),
+        }
+    );
+
+    display_dynamic_table(rows, &selected_columns);
+    Ok(())
+}
+
 async fn list_clones(args: &ListArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
     let deployment_id = args.deployment_id.as_ref().ok_or_else(|| {
         anyhow::anyhow!("Deployment ID is required for listing clones. Use -x <deployment_id>")
@@ -360,13 +561,26 @@ async fn list_deployments(
     config: &Config,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let mut deployments = deploy::list_deployments(config).await?;
+    let deployments = deploy::list_deployments(config).await?;
+    show_deployments(deployments, "deployments", args, output_format)
+}
+
+async fn list_f2(args: &ListArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let deployments = deploy::list_f2_deployments(config).await?;
+    show_deployments(deployments, "F2 deployments", args, output_format)
+}
 
+fn show_deployments(
+    mut deployments: Vec<ListDeploymentsResponse>,
+    label: &str,
+    args: &ListArgs,
+    output_format: OutputFormat,
+) -> Result<()> {
     if deployments.is_empty() {
         if output_format == OutputFormat::Json {
             print_json(&serde_json::json!([]));
         } else {
-            println!("{} No deployments found", "ℹ️".blue());
+            println!("{} No {} found", "ℹ️".blue(), label);
         }
         return Ok(());
     }
@@ -425,9 +639,10 @@ async fn list_deployments(
     }
 
     println!(
-        "{} Found {} deployments{}",
+        "{} Found {} {}{}",
         "✅".green(),
         total_count,
+        label,
         if let Some(limit) = args.limit {
             if limit < total_count {
                 format!(" (showing first {})", limit)
@@ -649,6 +864,18 @@ fn display_dynamic_table(rows: Vec<std::collections::HashMap<String, String>>, c
         return;
     }
 
+    let rows: Vec<std::collections::HashMap<String, String>> = rows
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|(col, value)| {
+                    let value = redact_field(&col, &value);
+                    (col, value)
+                })
+                .collect()
+        })
+        .collect();
+
     // Create a simple but properly formatted table
     let mut table_string = String::new();
 
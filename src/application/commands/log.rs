@@ -1,23 +1,50 @@
 use crate::application::auth;
-use crate::application::dto::log::{LogLevel, LogLine, LogResponse, LogSource};
+use crate::application::dto::log::{
+    GenericLogParser, LogLevel, LogLine, LogParser, LogResponse, LogSource,
+};
+use crate::application::duration::parse_duration;
+use crate::application::log_export::{ExportFormat, LogWriter, Rotation};
+use crate::application::services::deploy;
 use crate::config::config::Config;
+use crate::domain::errors::login_error::LoginError;
 use crate::structure::LogArgs;
 use anyhow::Result;
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
 use colored::Colorize;
-use reqwest::Client;
+use regex::Regex;
+use reqwest::{Client, StatusCode};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::io::{self, Write};
 use std::time::Duration;
 use tokio::time::sleep;
 
-use crate::application::output::{print_json, OutputFormat};
+use crate::application::output::{print_json, print_line, print_ndjson, OutputFormat};
 
 pub async fn log(args: &LogArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let filter = LogFilter::from_args(args)?;
     let jwt_token = auth::get_auth_token()?;
     let client = Client::new();
 
+    // The engine decides how lines are parsed; without it every format is tried
+    let database_provider = deploy::get_deployment(&args.deployment_id, config)
+        .await
+        .ok()
+        .map(|d| d.database_provider);
+    let database_provider = database_provider.as_deref();
+
     if args.follow {
-        return follow_logs(args, config, &client, &jwt_token, output_format).await;
+        return follow_logs(
+            args,
+            &filter,
+            database_provider,
+            config,
+            &client,
+            &jwt_token,
+            output_format,
+        )
+        .await;
     }
 
     let response = client
@@ -34,10 +61,16 @@ pub async fn log(args: &LogArgs, config: &Config, output_format: OutputFormat) -
 
         // Try to parse as structured JSON first
         if let Ok(log_response) = serde_json::from_str::<LogResponse>(&logs_text) {
-            display_structured_logs(&log_response, args, output_format)?;
+            display_structured_logs(
+                &log_response,
+                database_provider,
+                &filter,
+                args,
+                output_format,
+            )?;
         } else {
             // Fallback to raw text display
-            display_raw_logs(&logs_text, args, output_format)?;
+            display_raw_logs(&logs_text, &filter, args, output_format)?;
         }
     } else {
         let error_text = response.text().await.unwrap_or_default();
@@ -58,10 +91,12 @@ pub async fn log(args: &LogArgs, config: &Config, output_format: OutputFormat) -
 
 fn display_structured_logs(
     log_response: &LogResponse,
+    database_provider: Option<&str>,
+    filter: &LogFilter,
     args: &LogArgs,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let mut lines = log_response.parse_logs();
+    let mut lines = log_response.parse_logs_for(database_provider);
 
     // Count stdout vs stderr before filtering
     let stdout_count = lines
@@ -73,56 +108,29 @@ fn display_structured_logs(
         .filter(|line| matches!(line.source, LogSource::Stderr))
         .count();
 
-    // Apply date filters
-    if let Some(since_str) = &args.since {
-        if let Ok(since_dt) = parse_date_filter(since_str) {
-            lines.retain(|line| {
-                if let Some(timestamp) = &line.timestamp {
-                    if let Ok(line_dt) = parse_log_timestamp(timestamp) {
-                        line_dt >= since_dt
-                    } else {
-                        true // Keep if can't parse timestamp
-                    }
-                } else {
-                    true // Keep if no timestamp
-                }
-            });
-        }
-    }
+    let verdicts: Vec<Option<bool>> = lines.iter().map(|line| filter.verdict(line)).collect();
+    let selected = filter.select(&verdicts);
+    let total = selected.len();
+    let selected = limit_lines(selected, args);
 
-    if let Some(until_str) = &args.until {
-        if let Ok(until_dt) = parse_date_filter(until_str) {
-            lines.retain(|line| {
-                if let Some(timestamp) = &line.timestamp {
-                    if let Ok(line_dt) = parse_log_timestamp(timestamp) {
-                        line_dt <= until_dt
-                    } else {
-                        true // Keep if can't parse timestamp
-                    }
-                } else {
-                    true // Keep if no timestamp
-                }
-            });
+    if let Some(mut writer) = create_writer(args)? {
+        for sel in &selected {
+            lines[sel.index].context = sel.context;
+            writer.write_line(&lines[sel.index])?;
         }
+        writer.flush()?;
+        report_export(&writer, output_format);
+        return Ok(());
     }
 
-    // Apply source filters
-    if args.stdout_only {
-        lines.retain(|line| matches!(line.source, LogSource::Stdout));
-    } else if args.stderr_only {
-        lines.retain(|line| matches!(line.source, LogSource::Stderr));
-    }
-
-    // Apply line limit
-    if lines.len() > args.lines {
-        lines.truncate(args.lines);
-        if output_format == OutputFormat::Table {
-            println!(
-                "{} Showing last {} lines (use -n to change)",
-                "📄".yellow(),
-                args.lines
-            );
-        }
+    if total > selected.len() && output_format == OutputFormat::Table {
+        println!(
+            "{} Showing {} {} of {} lines (use -n to change)",
+            "📄".yellow(),
+            if args.head { "first" } else { "last" },
+            args.lines,
+            total
+        );
     }
 
     // Display header
@@ -146,7 +154,7 @@ fn display_structured_logs(
         }
     }
 
-    if lines.is_empty() {
+    if selected.is_empty() {
         if output_format == OutputFormat::Table {
             println!("{} No logs available", "ℹ️".blue());
         } else {
@@ -156,24 +164,54 @@ fn display_structured_logs(
         return Ok(());
     }
 
+    // None marks a "--" separator between groups of context lines
+    let mut lines: Vec<Option<LogLine>> = lines.into_iter().map(Some).collect();
+    let mut shown = Vec::with_capacity(selected.len());
+    for sel in &selected {
+        if let Some(mut line) = lines[sel.index].take() {
+            line.context = sel.context;
+            if sel.gap {
+                shown.push(None);
+            }
+            shown.push(Some(line));
+        }
+    }
+
     if output_format == OutputFormat::Json {
-        print_json(&lines);
+        let json: Vec<&LogLine> = shown.iter().flatten().collect();
+        print_json(&json);
         return Ok(());
     }
 
     // Display logs with pagination if needed
-    if lines.len() > 20 {
-        display_with_pagination(lines, args)?;
+    if shown.len() > 20 {
+        display_with_pagination(shown, args)?;
     } else {
-        display_logs_direct(lines, args)?;
+        display_logs_direct(shown, args)?;
     }
 
     Ok(())
 }
 
-fn display_raw_logs(logs_text: &str, args: &LogArgs, output_format: OutputFormat) -> Result<()> {
+fn display_raw_logs(
+    logs_text: &str,
+    filter: &LogFilter,
+    args: &LogArgs,
+    output_format: OutputFormat,
+) -> Result<()> {
     let lines: Vec<&str> = logs_text.lines().collect();
 
+    if let Some(mut writer) = create_writer(args)? {
+        let verdicts: Vec<Option<bool>> =
+            lines.iter().map(|line| filter.raw_verdict(line)).collect();
+        for sel in limit_lines(filter.select(&verdicts), args) {
+            writer.write_raw(lines[sel.index], sel.context)?;
+        }
+        writer.flush()?;
+        report_export(&writer, output_format);
+        return Ok(());
+    }
+
     if output_format == OutputFormat::Table {
         println!(
             "{} Deployment Logs for: {}",
@@ -192,40 +230,57 @@ fn display_raw_logs(logs_text: &str, args: &LogArgs, output_format: OutputFormat
         return Ok(());
     }
 
-    let start_idx = if lines.len() > args.lines {
-        lines.len() - args.lines
-    } else {
-        0
-    };
+    let verdicts: Vec<Option<bool>> = lines.iter().map(|line| filter.raw_verdict(line)).collect();
+    let selected = filter.select(&verdicts);
+    let total = selected.len();
+    let selected = limit_lines(selected, args);
 
-    if start_idx > 0 && output_format == OutputFormat::Table {
+    if total > selected.len() && output_format == OutputFormat::Table {
         println!(
-            "{} Showing last {} lines (use -n to change)",
+            "{} Showing {} {} of {} lines (use -n to change)",
             "📄".yellow(),
-            args.lines
+            if args.head { "first" } else { "last" },
+            args.lines,
+            total
         );
     }
 
     if output_format == OutputFormat::Json {
-        let json_lines: Vec<String> = lines[start_idx..].iter().map(|s| s.to_string()).collect();
+        let json_lines: Vec<&str> = selected.iter().map(|sel| lines[sel.index]).collect();
         print_json(&json_lines);
     } else {
-        for line in &lines[start_idx..] {
-            println!("{}", format_log_line_raw(line));
+        for sel in &selected {
+            if sel.gap {
+                println!("{}", "--".dimmed());
+            }
+            let line = lines[sel.index];
+            if sel.context {
+                println!("{}", line.dimmed());
+            } else {
+                println!("{}", format_log_line_raw(line));
+            }
         }
     }
 
     Ok(())
 }
 
-fn display_logs_direct(lines: Vec<LogLine>, args: &LogArgs) -> Result<()> {
+fn display_logs_direct(lines: Vec<Option<LogLine>>, args: &LogArgs) -> Result<()> {
     for line in lines {
-        println!("{}", format_log_line(&line, args.timestamps));
+        println!("{}", format_shown_line(line.as_ref(), args.timestamps));
     }
     Ok(())
 }
 
-fn display_with_pagination(lines: Vec<LogLine>, args: &LogArgs) -> Result<()> {
+/// Format a line, or the "--" separator between groups of context lines.
+fn format_shown_line(line: Option<&LogLine>, show_timestamps: bool) -> String {
+    match line {
+        Some(line) => format_log_line(line, show_timestamps),
+        None => "--".dimmed().to_string(),
+    }
+}
+
+fn display_with_pagination(lines: Vec<Option<LogLine>>, args: &LogArgs) -> Result<()> {
     let page_size = 20;
     let total_pages = (lines.len() + page_size - 1) / page_size;
     let mut current_page = total_pages; // Start from last page
@@ -252,7 +307,7 @@ fn display_with_pagination(lines: Vec<LogLine>, args: &LogArgs) -> Result<()> {
         println!("{}", "-".repeat(60).dimmed());
 
         for line in &lines[start_idx..end_idx] {
-            println!("{}", format_log_line(line, args.timestamps));
+            println!("{}", format_shown_line(line.as_ref(), args.timestamps));
         }
 
         println!("{}", "-".repeat(60).dimmed());
@@ -306,8 +361,10 @@ fn format_log_line(line: &LogLine, show_timestamps: bool) -> String {
 
     // No level indicator needed
 
-    // Add content with appropriate color based on source and level
+    // Add content with appropriate color based on source and level; context
+    // lines are dimmed so the matches stand out
     let content_color = match (&line.source, &line.level) {
+        _ if line.context => line.content.dimmed(),
         (LogSource::Stderr, LogLevel::Error) => line.content.red().bold(),
         (LogSource::Stderr, LogLevel::Warning) => line.content.yellow().bold(),
         (LogSource::Stderr, _) => line.content.red(),
@@ -335,98 +392,493 @@ fn format_log_line_raw(line: &str) -> String {
     }
 }
 
+/// Which lines `guepard log` shows. The stream and time window decide which
+/// lines are considered at all; `--level` and `--grep` then pick the matches,
+/// and `-C` keeps that many considered lines around each match.
+struct LogFilter {
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    stdout_only: bool,
+    stderr_only: bool,
+    level: Option<LevelFilter>,
+    pattern: Option<Regex>,
+    invert: bool,
+    context: usize,
+}
+
+/// `--level error` matches errors only, `--level warn+` warnings and errors.
+#[derive(Debug, PartialEq)]
+struct LevelFilter {
+    level: LogLevel,
+    or_above: bool,
+}
+
+impl LevelFilter {
+    fn parse(value: &str) -> Result<Self> {
+        let value = value.trim();
+        let (name, or_above) = match value.strip_suffix('+') {
+            Some(name) => (name, true),
+            None => (value, false),
+        };
+        let level = LogLevel::parse(name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid level '{}': use trace, debug, info, warn or error, optionally followed by '+'",
+                value
+            )
+        })?;
+        Ok(Self { level, or_above })
+    }
+
+    fn accepts(&self, level: LogLevel) -> bool {
+        if self.or_above {
+            level.severity() >= self.level.severity()
+        } else {
+            level == self.level
+        }
+    }
+}
+
+/// A line picked by `LogFilter::select`.
+#[derive(Debug, PartialEq)]
+struct Selected {
+    index: usize,
+    /// Shown only because it is near a match.
+    context: bool,
+    /// Considered lines were skipped before it, so a "--" separator goes first.
+    gap: bool,
+}
+
+impl LogFilter {
+    fn from_args(args: &LogArgs) -> Result<Self> {
+        let date = |value: &Option<String>| value.as_deref().map(parse_date_filter).transpose();
+        Ok(Self {
+            since: date(&args.since)?,
+            until: date(&args.until)?,
+            stdout_only: args.stdout_only,
+            stderr_only: args.stderr_only,
+            level: args.level.as_deref().map(LevelFilter::parse).transpose()?,
+            pattern: args
+                .grep
+                .as_deref()
+                .map(|p| {
+                    Regex::new(p).map_err(|e| anyhow::anyhow!("Invalid --grep pattern: {}", e))
+                })
+                .transpose()?,
+            invert: args.invert_grep,
+            context: args.context,
+        })
+    }
+
+    fn in_window(&self, timestamp: Option<DateTime<Utc>>) -> bool {
+        // Lines without a readable timestamp are kept
+        timestamp.is_none_or(|ts| {
+            self.since.is_none_or(|since| ts >= since) && self.until.is_none_or(|until| ts <= until)
+        })
+    }
+
+    fn matches(&self, level: LogLevel, text: &str) -> bool {
+        self.level.as_ref().is_none_or(|l| l.accepts(level))
+            && self
+                .pattern
+                .as_ref()
+                .is_none_or(|p| p.is_match(text) != self.invert)
+    }
+
+    /// None if the line is outside the stream or time window, otherwise
+    /// whether it matches.
+    fn verdict(&self, line: &LogLine) -> Option<bool> {
+        let stream = match line.source {
+            LogSource::Stdout => !self.stderr_only,
+            LogSource::Stderr => !self.stdout_only,
+        };
+        let timestamp = line
+            .timestamp
+            .as_deref()
+            .and_then(|ts| parse_log_timestamp(ts).ok());
+        (stream && self.in_window(timestamp)).then(|| self.matches(line.level, &line.content))
+    }
+
+    /// `verdict` for a line of the plain-text fallback, which has no stream.
+    fn raw_verdict(&self, line: &str) -> Option<bool> {
+        if line.trim().is_empty() {
+            return None;
+        }
+        let parsed = GenericLogParser.parse(line).unwrap_or_default();
+        self.in_window(parsed.timestamp)
+            .then(|| self.matches(parsed.level.unwrap_or(LogLevel::Info), line))
+    }
+
+    /// Pick the matching lines and their context from per-line verdicts.
+    /// Context counts considered lines only, as if the others were not there.
+    fn select(&self, verdicts: &[Option<bool>]) -> Vec<Selected> {
+        let considered: Vec<(usize, bool)> = verdicts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, verdict)| verdict.map(|matched| (index, matched)))
+            .collect();
+        let mut keep = vec![false; considered.len()];
+        for (pos, &(_, matched)) in considered.iter().enumerate() {
+            if matched {
+                let end = (pos + self.context + 1).min(considered.len());
+                keep[pos.saturating_sub(self.context)..end].fill(true);
+            }
+        }
+
+        let mut selected = Vec::new();
+        let mut previous: Option<usize> = None;
+        for (pos, &(index, matched)) in considered.iter().enumerate() {
+            if !keep[pos] {
+                continue;
+            }
+            selected.push(Selected {
+                index,
+                context: !matched,
+                gap: self.context > 0 && matches!(previous, Some(p) if p + 1 != pos),
+            });
+            previous = Some(pos);
+        }
+        selected
+    }
+}
+
+/// Keep the last `--lines` selected lines, or the first with `--head`.
+fn limit_lines(mut selected: Vec<Selected>, args: &LogArgs) -> Vec<Selected> {
+    if args.head {
+        selected.truncate(args.lines);
+    } else {
+        selected = selected.split_off(selected.len().saturating_sub(args.lines));
+    }
+    if let Some(first) = selected.first_mut() {
+        first.gap = false;
+    }
+    selected
+}
+
+/// The `--output-file` writer, if one was asked for.
+fn create_writer(args: &LogArgs) -> Result<Option<LogWriter>> {
+    let Some(path) = &args.output_file else {
+        return Ok(None);
+    };
+    let rotation = args.rotate.as_deref().map(Rotation::parse).transpose()?;
+    let format = ExportFormat::resolve(args.format.as_deref(), path);
+    LogWriter::create(path, format, rotation, args.keep, args.gzip).map(Some)
+}
+
+fn report_export(writer: &LogWriter, output_format: OutputFormat) {
+    if output_format == OutputFormat::Json {
+        print_json(&serde_json::json!({
+            "output_file": writer.path(),
+            "format": writer.format().name(),
+            "lines": writer.lines(),
+        }));
+    } else {
+        println!(
+            "{} Wrote {} log line(s) to {} ({})",
+            "✅".green(),
+            writer.lines(),
+            writer.path().display(),
+            writer.format().name()
+        );
+    }
+}
+
+/// Longest pause between polls while the log endpoint keeps failing.
+const FOLLOW_MAX_BACKOFF_SECS: u64 = 60;
+
+/// Remembers what follow mode has printed. The API returns its whole, bounded
+/// log buffer on every poll, so lines are matched by source, timestamp and
+/// content rather than by position: a truncated or rotated buffer neither
+/// hides new lines nor repeats old ones. Identical lines are counted, so a
+/// message logged twice is printed twice.
+#[derive(Default)]
+struct LogCursor {
+    seen: HashMap<u64, usize>,
+}
+
+impl LogCursor {
+    /// Indexes of the lines not printed yet, given as (source, timestamp, content).
+    fn advance(&mut self, lines: &[(&str, Option<&str>, &str)]) -> Vec<usize> {
+        let mut counts: HashMap<u64, usize> = HashMap::new();
+        let mut fresh = Vec::new();
+        for (index, (source, timestamp, content)) in lines.iter().enumerate() {
+            let mut hasher = DefaultHasher::new();
+            (source, timestamp, content).hash(&mut hasher);
+            let key = hasher.finish();
+            let count = counts.entry(key).or_insert(0);
+            *count += 1;
+
+            let printed = self.seen.get(&key).copied().unwrap_or(0);
+            if *count > printed {
+                fresh.push(index);
+            }
+        }
+        // Only the current buffer matters next time; this keeps memory bounded
+        self.seen = counts;
+        fresh
+    }
+}
+
+/// Pause before the next poll after `failures` consecutive failed polls.
+fn follow_delay(interval: Duration, failures: u32) -> Duration {
+    let factor = 2u32.saturating_pow(failures.min(16));
+    interval
+        .saturating_mul(factor)
+        .min(Duration::from_secs(FOLLOW_MAX_BACKOFF_SECS).max(interval))
+}
+
+/// What follow mode carries from one poll to the next.
+struct FollowState {
+    cursor: LogCursor,
+    writer: Option<LogWriter>,
+    /// No poll has succeeded yet.
+    first: bool,
+}
+
+struct FollowSummary {
+    started: std::time::Instant,
+    printed: usize,
+    polls: usize,
+    reconnects: usize,
+}
+
+async fn fetch_logs(client: &Client, url: &str, jwt_token: &str) -> Result<String> {
+    let response = client
+        .get(url)
+        .header("Authorization", format!("Bearer {}", jwt_token))
+        .send()
+        .await?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response.text().await?);
+    }
+    let text = response.text().await.unwrap_or_default();
+    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+        return Err(LoginError::SessionError(format!(
+            "log stream refused ({}): {}. Run 'guepard login' and try again",
+            status, text
+        ))
+        .into());
+    }
+    Err(anyhow::anyhow!("Status {}: {}", status, text))
+}
+
+/// Print (or write to `--output-file`) the lines of one poll that were not
+/// printed before. The first poll shows only the last `--lines` lines, like
+/// `tail -f`. Filters run over the whole buffer, so context lines that arrive
+/// after a match are still shown.
+fn print_new_lines(
+    logs_text: &str,
+    database_provider: Option<&str>,
+    filter: &LogFilter,
+    state: &mut FollowState,
+    args: &LogArgs,
+    output_format: OutputFormat,
+) -> Result<usize> {
+    let first = state.first;
+    let mut printed = 0;
+    if let Ok(log_response) = serde_json::from_str::<LogResponse>(logs_text) {
+        let mut lines = log_response.parse_logs_for(database_provider);
+        let verdicts: Vec<Option<bool>> = lines.iter().map(|line| filter.verdict(line)).collect();
+        let selected = filter.select(&verdicts);
+        let keys: Vec<(&str, Option<&str>, &str)> = selected
+            .iter()
+            .map(|sel| {
+                let line = &lines[sel.index];
+                let source = match line.source {
+                    LogSource::Stdout => "stdout",
+                    LogSource::Stderr => "stderr",
+                };
+                (source, line.timestamp.as_deref(), line.content.as_str())
+            })
+            .collect();
+        let mut fresh = state.cursor.advance(&keys);
+        if first {
+            fresh = fresh.split_off(fresh.len().saturating_sub(args.lines));
+        }
+        for sel in fresh.into_iter().map(|i| &selected[i]) {
+            let line = &mut lines[sel.index];
+            line.context = sel.context;
+            if let Some(writer) = state.writer.as_mut() {
+                writer.write_line(line)?;
+            } else if output_format == OutputFormat::Json {
+                print_ndjson(line);
+            } else {
+                if sel.gap && !(first && printed == 0) {
+                    print_line(&"--".dimmed().to_string());
+                }
+                print_line(&format_log_line(line, args.timestamps));
+            }
+            printed += 1;
+        }
+    } else {
+        // Raw text fallback
+        let lines: Vec<&str> = logs_text.lines().filter(|l| !l.trim().is_empty()).collect();
+        let verdicts: Vec<Option<bool>> =
+            lines.iter().map(|line| filter.raw_verdict(line)).collect();
+        let selected = filter.select(&verdicts);
+        let keys: Vec<(&str, Option<&str>, &str)> = selected
+            .iter()
+            .map(|sel| ("raw", None, lines[sel.index]))
+            .collect();
+        let mut fresh = state.cursor.advance(&keys);
+        if first {
+            fresh = fresh.split_off(fresh.len().saturating_sub(args.lines));
+        }
+        for sel in fresh.into_iter().map(|i| &selected[i]) {
+            let line = lines[sel.index];
+            if let Some(writer) = state.writer.as_mut() {
+                writer.write_raw(line, sel.context)?;
+            } else if output_format == OutputFormat::Json {
+                print_ndjson(&serde_json::json!({
+                    "timestamp": null,
+                    "content": line,
+                    "source": "Unknown",
+                    "context": sel.context
+                }));
+            } else {
+                if sel.gap && !(first && printed == 0) {
+                    print_line(&"--".dimmed().to_string());
+                }
+                if sel.context {
+                    print_line(&line.dimmed().to_string());
+                } else {
+                    print_line(&format_log_line_raw(line));
+                }
+            }
+            printed += 1;
+        }
+    }
+    if let Some(writer) = state.writer.as_mut() {
+        writer.flush()?;
+    }
+    state.first = false;
+    Ok(printed)
+}
+
 async fn follow_logs(
     args: &LogArgs,
+    filter: &LogFilter,
+    database_provider: Option<&str>,
     config: &Config,
     client: &Client,
     jwt_token: &str,
     output_format: OutputFormat,
 ) -> Result<()> {
+    let mut state = FollowState {
+        cursor: LogCursor::default(),
+        writer: create_writer(args)?,
+        first: true,
+    };
+
     if output_format == OutputFormat::Table {
         println!(
             "{} Following logs for deployment: {} (Press Ctrl+C to stop)",
             "👀".green(),
             args.deployment_id
         );
+        if let Some(path) = &args.output_file {
+            println!("{} Writing lines to {}", "💾".cyan(), path.display());
+        }
         println!("{}", "=".repeat(60).dimmed());
     }
 
-    let mut last_line_count = 0;
+    let url = format!("{}/deploy/{}/logs", config.api_url, args.deployment_id);
+    let interval = Duration::from_secs(args.interval.max(1));
+    let mut failures = 0u32;
+    let mut summary = FollowSummary {
+        started: std::time::Instant::now(),
+        printed: 0,
+        polls: 0,
+        reconnects: 0,
+    };
 
     loop {
-        let response = client
-            .get(format!(
-                "{}/deploy/{}/logs",
-                config.api_url, args.deployment_id
-            ))
-            .header("Authorization", format!("Bearer {}", jwt_token))
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            let current_logs_text = response.text().await?;
-
-            // Try to parse as structured JSON
-            if let Ok(log_response) = serde_json::from_str::<LogResponse>(&current_logs_text) {
-                let lines = log_response.parse_logs();
-                let current_count = lines.len();
-
-                if current_count > last_line_count {
-                    // Get only the new lines
-                    let new_lines = lines.into_iter().skip(last_line_count);
-
-                    for line in new_lines {
-                        // Apply filters if any
-                        if args.stdout_only && !matches!(line.source, LogSource::Stdout) {
-                            continue;
-                        }
-                        if args.stderr_only && !matches!(line.source, LogSource::Stderr) {
-                            continue;
-                        }
-
-                        if output_format == OutputFormat::Json {
-                            if let Ok(json) = serde_json::to_string(&line) {
-                                println!("{}", json);
-                            }
-                        } else {
-                            println!("{}", format_log_line(&line, args.timestamps));
-                        }
-                    }
-                    last_line_count = current_count;
-                }
-            } else {
-                // Raw text fallback
-                let lines: Vec<&str> = current_logs_text.lines().collect();
-                let current_count = lines.len();
-
-                if current_count > last_line_count {
-                    let new_lines = &lines[last_line_count..];
-
-                    for line in new_lines {
-                        if output_format == OutputFormat::Json {
-                            println!(
-                                "{}",
-                                serde_json::json!({ "content": line, "source": "Unknown" })
-                            );
-                        } else {
-                            println!("{}", format_log_line_raw(line));
-                        }
-                    }
-                    last_line_count = current_count;
+        let result = tokio::select! {
+            result = fetch_logs(client, &url, jwt_token) => result,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        summary.polls += 1;
+
+        match result {
+            Ok(logs_text) => {
+                if failures > 0 {
+                    eprintln!(
+                        "{} Reconnected to the log stream after {} failed poll(s)",
+                        "🔄".green(),
+                        failures
+                    );
+                    summary.reconnects += 1;
+                    failures = 0;
                 }
+                summary.printed += print_new_lines(
+                    &logs_text,
+                    database_provider,
+                    filter,
+                    &mut state,
+                    args,
+                    output_format,
+                )?;
+            }
+            Err(e) if e.downcast_ref::<LoginError>().is_some() => return Err(e),
+            Err(e) => {
+                failures += 1;
+                eprintln!(
+                    "{} Log stream interrupted: {}. Retrying in {}s",
+                    "⚠️".yellow(),
+                    e,
+                    follow_delay(interval, failures).as_secs()
+                );
             }
         }
 
-        sleep(Duration::from_secs(2)).await;
+        tokio::select! {
+            _ = sleep(follow_delay(interval, failures)) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
     }
+
+    // Keep stdout pure NDJSON; the summary goes to stderr
+    let destination = match &state.writer {
+        Some(writer) => format!("written to {}", writer.path().display()),
+        None => "printed".to_string(),
+    };
+    eprintln!(
+        "{} Stopped following after {}s: {} line(s) {}, {} poll(s), {} reconnect(s)",
+        "👋".blue(),
+        summary.started.elapsed().as_secs(),
+        summary.printed,
+        destination,
+        summary.polls,
+        summary.reconnects
+    );
+    Ok(())
 }
 
-fn parse_date_filter(date_str: &str) -> Result<DateTime<Utc>> {
+pub(crate) fn parse_date_filter(date_str: &str) -> Result<DateTime<Utc>> {
+    parse_date_filter_at(date_str, Utc::now())
+}
+
+/// Parse an absolute date or a relative expression ("2 hours ago", "yesterday")
+/// against the given reference time.
+pub(crate) fn parse_date_filter_at(date_str: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let date_str = date_str.trim();
+
+    if let Some(relative) = parse_relative_date(date_str, now) {
+        return Ok(relative);
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(date_str) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
     // Try different date formats
     let formats = [
-        "%Y-%m-%d %H:%M:%S", // 2025-10-08 08:52:16
-        "%Y-%m-%d",          // 2025-10-08
-        "%Y-%m-%d %H:%M",    // 2025-10-08 08:52
+        "%Y-%m-%d %H:%M:%S",    // 2025-10-08 08:52:16
+        "%Y-%m-%d",             // 2025-10-08
+        "%Y-%m-%d %H:%M",       // 2025-10-08 08:52
+        "%Y-%m-%dT%H:%M:%S",    // 2025-10-08T08:52:16
+        "%Y-%m-%dT%H:%M:%S%.f", // 2025-10-08T08:52:16.123456
+        "%Y-%m-%dT%H:%M",       // 2025-10-08T08:52
     ];
 
     for format in &formats {
@@ -444,8 +896,38 @@ fn parse_date_filter(date_str: &str) -> Result<DateTime<Utc>> {
     Err(anyhow::anyhow!("Invalid date format: {}", date_str))
 }
 
+/// Relative expressions: "now", "today", "yesterday", "<n> <unit> ago" and the
+/// compact "<n><unit>" form ("30m", "2h", "7d"). Amounts reaching past the
+/// range of a date are rejected rather than wrapped.
+fn parse_relative_date(date_str: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let lower = date_str.to_lowercase();
+    let midnight = |dt: DateTime<Utc>| {
+        DateTime::from_naive_utc_and_offset(dt.date_naive().and_hms_opt(0, 0, 0).unwrap(), Utc)
+    };
+
+    match lower.as_str() {
+        "now" => return Some(now),
+        "today" => return Some(midnight(now)),
+        "yesterday" => return Some(midnight(now - chrono::Duration::days(1))),
+        _ => {}
+    }
+
+    // A unit is required: a bare number is not a relative date
+    let expr = lower.strip_suffix("ago").unwrap_or(&lower).trim();
+    if !expr.starts_with(|c: char| c.is_ascii_digit())
+        || expr.ends_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let duration = TimeDelta::from_std(parse_duration(expr).ok()?).ok()?;
+    now.checked_sub_signed(duration)
+}
+
 fn parse_log_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
-    // Parse PostgreSQL timestamp format: "2025-10-08 08:52:16.178 UTC"
+    // LogLine timestamps are RFC 3339; older ones look like "2025-10-08 08:52:16.178 UTC"
+    if let Ok(dt) = DateTime::parse_from_rfc3339(timestamp) {
+        return Ok(dt.with_timezone(&Utc));
+    }
     let cleaned = timestamp.replace(" UTC", "");
     let formats = [
         "%Y-%m-%d %H:%M:%S%.3f", // With milliseconds
@@ -460,3 +942,177 @@ fn parse_log_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
 
     Err(anyhow::anyhow!("Invalid timestamp format: {}", timestamp))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_log_cursor_survives_truncation_and_repeats() {
+        let mut cursor = LogCursor::default();
+        let first = [
+            ("stdout", Some("2025-06-01 10:00:00.000 UTC"), "a"),
+            ("stdout", Some("2025-06-01 10:00:01.000 UTC"), "b"),
+            ("stdout", None, "checkpoint"),
+        ];
+        assert_eq!(cursor.advance(&first), vec![0, 1, 2]);
+        assert!(cursor.advance(&first).is_empty());
+
+        // The server dropped "a" and appended "c" and a repeated message
+        let second = [
+            ("stdout", Some("2025-06-01 10:00:01.000 UTC"), "b"),
+            ("stdout", Some("2025-06-01 10:00:02.000 UTC"), "c"),
+            ("stdout", None, "checkpoint"),
+            ("stdout", None, "checkpoint"),
+        ];
+        assert_eq!(cursor.advance(&second), vec![1, 3]);
+
+        // A stderr line that arrives a poll after newer stdout lines is still printed
+        let third = [
+            ("stdout", Some("2025-06-01 10:00:01.000 UTC"), "b"),
+            ("stderr", Some("2025-06-01 10:00:01.500 UTC"), "late"),
+            ("stdout", Some("2025-06-01 10:00:02.000 UTC"), "c"),
+            ("stdout", None, "checkpoint"),
+            ("stdout", None, "checkpoint"),
+        ];
+        assert_eq!(cursor.advance(&third), vec![1]);
+    }
+
+    #[test]
+    fn test_follow_delay_backs_off() {
+        let interval = Duration::from_secs(2);
+        assert_eq!(follow_delay(interval, 0), interval);
+        assert_eq!(follow_delay(interval, 2), Duration::from_secs(8));
+        assert_eq!(
+            follow_delay(interval, 30),
+            Duration::from_secs(FOLLOW_MAX_BACKOFF_SECS)
+        );
+    }
+
+    fn grep_filter(pattern: &str, context: usize) -> LogFilter {
+        LogFilter {
+            since: None,
+            until: None,
+            stdout_only: false,
+            stderr_only: false,
+            level: None,
+            pattern: Some(Regex::new(pattern).unwrap()),
+            invert: false,
+            context,
+        }
+    }
+
+    #[test]
+    fn test_level_filter() {
+        let warn_up = LevelFilter::parse("warn+").unwrap();
+        assert!(warn_up.accepts(LogLevel::Error));
+        assert!(warn_up.accepts(LogLevel::Warning));
+        assert!(!warn_up.accepts(LogLevel::Info));
+
+        let error = LevelFilter::parse("ERROR").unwrap();
+        assert!(error.accepts(LogLevel::Error));
+        let debug = LevelFilter::parse("debug").unwrap();
+        assert!(!debug.accepts(LogLevel::Trace));
+        assert!(LevelFilter::parse("loud+").is_err());
+    }
+
+    #[test]
+    fn test_select_matches_with_context() {
+        let lines = [
+            "2026-01-15 10:00:00 LOG: checkpoint starting",
+            "2026-01-15 10:00:01 LOG: statement: UPDATE a",
+            "2026-01-15 10:00:02 ERROR: deadlock detected",
+            "2026-01-15 10:00:03 LOG: statement: UPDATE b",
+            "2026-01-15 10:00:04 LOG: checkpoint complete",
+            "2026-01-15 10:00:05 LOG: autovacuum",
+            "2026-01-15 10:00:06 ERROR: deadlock detected",
+        ];
+        let filter = grep_filter("deadlock", 1);
+        let verdicts: Vec<Option<bool>> =
+            lines.iter().map(|line| filter.raw_verdict(line)).collect();
+        let picked: Vec<(usize, bool, bool)> = filter
+            .select(&verdicts)
+            .iter()
+            .map(|sel| (sel.index, sel.context, sel.gap))
+            .collect();
+        assert_eq!(
+            picked,
+            vec![
+                (1, true, false),
+                (2, false, false),
+                (3, true, false),
+                (5, true, true),
+                (6, false, false),
+            ]
+        );
+
+        // Lines outside the time window are neither matches nor context
+        let mut filter = grep_filter("deadlock", 1);
+        filter.since = Some(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 2).unwrap());
+        filter.invert = true;
+        filter.level = Some(LevelFilter::parse("info").unwrap());
+        let verdicts: Vec<Option<bool>> =
+            lines.iter().map(|line| filter.raw_verdict(line)).collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                None,
+                None,
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false)
+            ]
+        );
+    }
+
+    fn reference_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 1, 14, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_date_filter_absolute() {
+        let now = reference_now();
+        assert_eq!(
+            parse_date_filter_at("2025-06-01 14:00", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 1, 14, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_date_filter_at("2025-06-01", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_date_filter_at("2025-06-01T16:00:00+02:00", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 1, 14, 0, 0).unwrap()
+        );
+        assert!(parse_date_filter_at("last tuesday", now).is_err());
+    }
+
+    #[test]
+    fn test_parse_date_filter_relative() {
+        let now = reference_now();
+        assert_eq!(
+            parse_date_filter_at("2 hours ago", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 1, 12, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_date_filter_at("30m", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 1, 14, 0, 0).unwrap()
+        );
+        assert_eq!(
+            parse_date_filter_at("1 day ago", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 5, 31, 14, 30, 0).unwrap()
+        );
+        assert_eq!(
+            parse_date_filter_at("yesterday", now).unwrap(),
+            Utc.with_ymd_and_hms(2025, 5, 31, 0, 0, 0).unwrap()
+        );
+        assert_eq!(parse_date_filter_at("now", now).unwrap(), now);
+        assert!(parse_date_filter_at("5 fortnights ago", now).is_err());
+        // Out of range for a date, not a panic
+        assert!(parse_date_filter_at("99999999999999h", now).is_err());
+        assert!(parse_date_filter_at("9999999999999 weeks ago", now).is_err());
+    }
+}
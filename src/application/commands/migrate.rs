@@ -0,0 +1,253 @@
+use crate::application::commands::checkout::{attached_branch_id, find_branch};
+use crate::application::commands::connection::{resolve_params, shell_quote};
+use crate::application::dto::commit::CreateCommitRequest;
+use crate::application::output::{mask_uri, print_json, OutputFormat};
+use crate::application::process::wait_in_foreground;
+use crate::application::services::usage::{self, QuotaResource};
+use crate::application::services::{branch, commit};
+use crate::config::config::{resolve_deployment_id, Config};
+use crate::domain::errors::exit_error::ExitError;
+use crate::structure::MigrateArgs;
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::io::ErrorKind;
+use tokio::process::Command;
+
+/// Longest command line kept in a snapshot comment.
+const MAX_COMMENT_COMMAND_LEN: usize = 200;
+
+#[derive(Serialize)]
+struct MigrationReport {
+    deployment_id: String,
+    branch_id: String,
+    command: String,
+    exit_code: Option<i32>,
+    success: bool,
+    pre_snapshot_id: String,
+    post_snapshot_id: Option<String>,
+    restored: bool,
+}
+
+/// The command as a shell would read it, with passwords in URIs or
+/// `password=` arguments masked and shortened for snapshot comments.
+fn display_command(command: &[String]) -> String {
+    let line = command
+        .iter()
+        .map(|arg| shell_quote(&mask_uri(arg)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if line.chars().count() > MAX_COMMENT_COMMAND_LEN {
+        let truncated: String = line.chars().take(MAX_COMMENT_COMMAND_LEN - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        line
+    }
+}
+
+fn pre_comment(command: &str) -> String {
+    format!("Before migration: {}", command)
+}
+
+fn post_comment(command: &str, exit_code: Option<i32>) -> String {
+    let status = match exit_code {
+        Some(0) => "succeeded".to_string(),
+        Some(code) => format!("failed, exit {}", code),
+        None => "failed, killed by signal".to_string(),
+    };
+    format!("After migration ({}): {}", status, command)
+}
+
+/// The branch to migrate. Only the checked-out branch is reachable through the
+/// compute, so any other branch is refused.
+async fn resolve_branch(
+    deployment_id: &str,
+    selector: Option<&str>,
+    config: &Config,
+) -> Result<String> {
+    let attached = attached_branch_id(deployment_id, config).await?;
+    let Some(selector) = selector else {
+        return Ok(attached);
+    };
+
+    let branches = branch::list_branches(deployment_id, config).await?;
+    let target = find_branch(&branches, selector).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Branch {} not found for deployment {}",
+            selector,
+            deployment_id
+        )
+    })?;
+    if target.id != attached {
+        return Err(anyhow::anyhow!(
+            "Branch {} is not checked out. Run 'guepard checkout -x {} -c {}' first",
+            selector,
+            deployment_id,
+            target.id
+        ));
+    }
+    Ok(target.id.clone())
+}
+
+pub async fn migrate(
+    args: &MigrateArgs,
+    config: &Config,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let deployment_id = resolve_deployment_id(args.deployment_id.as_deref())?;
+    let branch_id = resolve_branch(&deployment_id, args.branch.as_deref(), config).await?;
+    let params = resolve_params(&deployment_id, None, None, config).await?;
+    let command_line = display_command(&args.command);
+
+    // Both snapshots count against the quota; fail before touching the database
+    usage::ensure_quota(QuotaResource::Snapshot, config).await?;
+    usage::ensure_quota(QuotaResource::Snapshot, config).await?;
+
+    let pre = commit::create_commit(
+        &deployment_id,
+        &branch_id,
+        CreateCommitRequest {
+            snapshot_comment: pre_comment(&command_line),
+        },
+        config,
+    )
+    .await?;
+    if output_format == OutputFormat::Table {
+        println!("{} Pre-migration snapshot: {}", "📸".green(), pre.id.cyan());
+        println!("{} Running: {}", "🚚".cyan(), command_line);
+    }
+
+    let mut child = Command::new(&args.command[0])
+        .args(&args.command[1..])
+        .envs(params.env_vars())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => anyhow::anyhow!(
+                "{} not found on PATH. The pre-migration snapshot {} was taken; nothing else changed",
+                args.command[0],
+                pre.id
+            ),
+            _ => anyhow::anyhow!("Failed to start {}: {}", args.command[0], e),
+        })?;
+    // Ctrl+C goes to the migration tool; guepard stays to snapshot and restore
+    let (status, interrupted) = wait_in_foreground(&mut child).await?;
+    let succeeded = status.success() && !interrupted;
+    let exit_code = match status.code() {
+        // An interrupted run is never a success, even if the tool exited 0
+        Some(0) if interrupted => Some(130),
+        code => code,
+    };
+    if interrupted && output_format == OutputFormat::Table {
+        eprintln!("{} Migration interrupted", "⚠️".yellow());
+    }
+
+    let post = commit::create_commit(
+        &deployment_id,
+        &branch_id,
+        CreateCommitRequest {
+            snapshot_comment: post_comment(&command_line, exit_code),
+        },
+        config,
+    )
+    .await;
+    let post_snapshot_id = match post {
+        Ok(snapshot) => Some(snapshot.id),
+        Err(e) => {
+            eprintln!("{} Post-migration snapshot failed: {}", "⚠️".yellow(), e);
+            None
+        }
+    };
+
+    let mut restored = false;
+    if !succeeded {
+        if args.no_restore {
+            eprintln!(
+                "{} Migration failed; leaving the database as is (--no-restore). Restore with 'guepard checkout -x {} -c {} -s {} --discard-changes true'",
+                "⚠️".yellow(),
+                deployment_id,
+                branch_id,
+                pre.id
+            );
+        } else {
+            eprintln!(
+                "{} Migration failed; restoring pre-migration snapshot {}",
+                "⏪".yellow(),
+                pre.id
+            );
+            branch::checkout_snapshot(&deployment_id, &branch_id, &pre.id, true, config)
+                .await
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "Migration failed and restoring pre-migration snapshot {} failed: {}. Restore with 'guepard checkout -x {} -c {} -s {} --discard-changes true'",
+                        pre.id,
+                        e,
+                        deployment_id,
+                        branch_id,
+                        pre.id
+                    )
+                })?;
+            restored = true;
+        }
+    }
+
+    let report = MigrationReport {
+        deployment_id,
+        branch_id,
+        command: command_line,
+        exit_code,
+        success: succeeded,
+        pre_snapshot_id: pre.id,
+        post_snapshot_id,
+        restored,
+    };
+    if output_format == OutputFormat::Json {
+        print_json(&report);
+    } else if report.success {
+        println!(
+            "{} Migration succeeded. Post-migration snapshot: {}",
+            "✅".green(),
+            report.post_snapshot_id.as_deref().unwrap_or("-").cyan()
+        );
+    } else if report.restored {
+        println!(
+            "{} Migration failed and the branch was restored to {}",
+            "❌".red(),
+            report.pre_snapshot_id.cyan()
+        );
+    }
+
+    if !succeeded {
+        // Hand the migration tool's exit status back to the caller's shell
+        return Err(ExitError::Child {
+            program: args.command[0].clone(),
+            code: exit_code.unwrap_or(1),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_comments() {
+        let command = display_command(&[
+            "flyway".to_string(),
+            "-url=jdbc:x".to_string(),
+            "migrate it".to_string(),
+        ]);
+        assert_eq!(command, "flyway -url=jdbc:x 'migrate it'");
+        assert_eq!(
+            post_comment(&command, Some(0)),
+            "After migration (succeeded): flyway -url=jdbc:x 'migrate it'"
+        );
+        assert!(post_comment("sqlx migrate run", Some(2)).contains("failed, exit 2"));
+        assert!(!display_command(&["-password=s3cret".to_string()]).contains("s3cret"));
+
+        let long = display_command(&["x".repeat(500)]);
+        assert_eq!(long.chars().count(), MAX_COMMENT_COMMAND_LEN);
+        assert!(long.ends_with("..."));
+    }
+}
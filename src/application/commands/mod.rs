@@ -1,13 +1,24 @@
+pub mod apply;
 pub mod branch;
+pub mod bulk;
 pub mod checkout;
 pub mod clone;
 pub mod commit;
 pub mod compute;
 pub mod config;
+pub mod connect;
+pub mod connection;
 pub mod deploy;
+pub mod doctor;
+pub mod env;
+pub mod exec;
 pub mod list;
 pub mod log;
 pub mod login;
 pub mod logout;
+pub mod migrate;
+pub mod status;
 pub mod tenet;
 pub mod usage;
+pub mod wait;
+pub mod wizard;
@@ -0,0 +1,357 @@
+use crate::application::dto::commit::GetCommitResponse;
+use crate::application::dto::compute::ComputeState;
+use crate::application::output::{print_json, OutputFormat};
+use crate::application::services::{branch, commit, compute, deploy};
+use crate::config::config::{resolve_deployment_id, Config};
+use crate::structure::StatusArgs;
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use colored::Colorize;
+use serde::Serialize;
+
+/// Snapshot statuses that mean the snapshot is fully written.
+const READY_SNAPSHOT_STATUSES: [&str; 2] = ["CREATED", "COMPLETED"];
+
+// The JSON shape below is consumed by shell prompts and CI gates: every key is
+// always present and unknown values are emitted as null.
+#[derive(Serialize)]
+struct StatusReport {
+    deployment_id: String,
+    name: String,
+    repository_name: String,
+    deployment_status: String,
+    database_provider: String,
+    database_version: String,
+    branch: BranchStatus,
+    snapshot: Option<SnapshotStatus>,
+    last_snapshot: Option<SnapshotStatus>,
+    seconds_since_last_snapshot: Option<i64>,
+    compute: ComputeHealth,
+    endpoint: EndpointStatus,
+    pending_snapshots: Vec<SnapshotStatus>,
+}
+
+#[derive(Serialize)]
+struct BranchStatus {
+    id: String,
+    name: Option<String>,
+    is_ephemeral: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct SnapshotStatus {
+    id: String,
+    name: Option<String>,
+    comment: Option<String>,
+    status: Option<String>,
+    created_date: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ComputeHealth {
+    healthy: Option<bool>,
+    state: Option<ComputeState>,
+    status: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EndpointStatus {
+    fqdn: String,
+    port: Option<i32>,
+    connection_string: Option<String>,
+}
+
+impl From<&GetCommitResponse> for SnapshotStatus {
+    fn from(commit: &GetCommitResponse) -> Self {
+        SnapshotStatus {
+            id: commit.id.clone(),
+            name: Some(commit.name.clone()),
+            comment: Some(commit.snapshot_comment.clone()),
+            status: Some(commit.status.clone()),
+            created_date: Some(commit.created_date.clone()),
+        }
+    }
+}
+
+pub async fn status(args: &StatusArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let deployment_id = resolve_deployment_id(args.deployment_id.as_deref())?;
+
+    let deployment = deploy::get_deployment(&deployment_id, config).await?;
+    // A failed lookup must not read as "no branch" or "never snapshotted"
+    let compute_info = compute::list_compute(&deployment_id, config).await?;
+    let branches = branch::list_branches(&deployment_id, config).await?;
+    let commits = commit::list_all_commits(&deployment_id, config).await?;
+
+    let compute_health = match compute::get_state(&deployment_id, config).await {
+        Ok((state, result)) => ComputeHealth {
+            healthy: Some(state == ComputeState::Running),
+            state: Some(state),
+            status: result.status,
+            message: result.message,
+        },
+        Err(e) => ComputeHealth {
+            healthy: None,
+            state: None,
+            status: None,
+            message: Some(e.to_string()),
+        },
+    };
+
+    let attached_branch_id = compute_info
+        .branch_id
+        .clone()
+        .unwrap_or_else(|| compute_info.attached_branch.clone());
+
+    let attached_branch = branches.iter().find(|b| b.id == attached_branch_id);
+
+    let branch_status = BranchStatus {
+        id: attached_branch_id.clone(),
+        name: attached_branch.and_then(|b| b.branch_name.clone().or(b.label_name.clone())),
+        is_ephemeral: attached_branch.map(|b| b.is_ephemeral),
+    };
+
+    let snapshot_id = attached_branch
+        .map(|b| b.snapshot_id.clone())
+        .or_else(|| deployment.snapshot_id.clone());
+    let snapshot = snapshot_id.map(|id| {
+        commits
+            .iter()
+            .find(|c| c.id == id)
+            .map(SnapshotStatus::from)
+            .unwrap_or(SnapshotStatus {
+                id,
+                name: None,
+                comment: None,
+                status: None,
+                created_date: None,
+            })
+    });
+
+    let branch_commits: Vec<&GetCommitResponse> = commits
+        .iter()
+        .filter(|c| c.dataset_id.as_deref() == Some(attached_branch_id.as_str()))
+        .collect();
+
+    let last_snapshot = latest_snapshot(&branch_commits);
+    let seconds_since_last_snapshot = last_snapshot
+        .and_then(|c| parse_api_timestamp(&c.created_date))
+        .map(|created| {
+            Utc::now()
+                .signed_duration_since(created)
+                .num_seconds()
+                .max(0)
+        });
+
+    let pending_snapshots: Vec<SnapshotStatus> = commits
+        .iter()
+        .filter(|c| is_pending(&c.status))
+        .map(SnapshotStatus::from)
+        .collect();
+
+    let endpoint = EndpointStatus {
+        fqdn: compute_info.fqdn.clone(),
+        port: Some(compute_info.port),
+        connection_string: Some(compute_info.connection_string.clone()),
+    };
+
+    let report = StatusReport {
+        deployment_id: deployment.id,
+        name: deployment.name,
+        repository_name: deployment.repository_name,
+        deployment_status: deployment.status,
+        database_provider: deployment.database_provider,
+        database_version: deployment.database_version,
+        branch: branch_status,
+        snapshot,
+        last_snapshot: last_snapshot.map(SnapshotStatus::from),
+        seconds_since_last_snapshot,
+        compute: compute_health,
+        endpoint,
+        pending_snapshots,
+    };
+
+    if output_format == OutputFormat::Json {
+        print_json(&report);
+        return Ok(());
+    }
+
+    display_status(&report);
+    Ok(())
+}
+
+fn display_status(report: &StatusReport) {
+    println!(
+        "{} {} ({})",
+        "📍 On deployment".blue().bold(),
+        report.repository_name.cyan().bold(),
+        report.deployment_id
+    );
+    println!(
+        "  {} {} {} · {}",
+        "Database:".yellow(),
+        report.database_provider,
+        report.database_version,
+        report.deployment_status
+    );
+
+    println!(
+        "  {} * {} ({})",
+        "Branch:".yellow(),
+        report
+            .branch
+            .name
+            .as_deref()
+            .unwrap_or("unnamed")
+            .green()
+            .bold(),
+        report.branch.id
+    );
+
+    match &report.snapshot {
+        Some(snapshot) => println!(
+            "  {} {} {}",
+            "Snapshot:".yellow(),
+            snapshot.id,
+            snapshot
+                .comment
+                .as_deref()
+                .map(|c| format!("\"{}\"", c))
+                .unwrap_or_default()
+                .dimmed()
+        ),
+        None => println!("  {} {}", "Snapshot:".yellow(), "unknown".dimmed()),
+    }
+
+    match report.seconds_since_last_snapshot {
+        Some(seconds) => println!(
+            "  {} {} ago",
+            "Last snapshot:".yellow(),
+            format_age(seconds)
+        ),
+        None => println!("  {} {}", "Last snapshot:".yellow(), "never".dimmed()),
+    }
+
+    let health = match report.compute.state {
+        Some(ComputeState::Running) => "Running".green().bold(),
+        Some(state) => state.to_string().red().bold(),
+        None => "Unknown".yellow().bold(),
+    };
+    match &report.compute.message {
+        Some(message) => println!("  {} {} ({})", "Compute:".yellow(), health, message),
+        None => println!("  {} {}", "Compute:".yellow(), health),
+    }
+
+    let host = match report.endpoint.port {
+        Some(port) => format!("{}:{}", report.endpoint.fqdn, port),
+        None => report.endpoint.fqdn.clone(),
+    };
+    println!("  {} {}", "Endpoint:".yellow(), host.cyan());
+
+    if report.pending_snapshots.is_empty() {
+        println!("  {} none", "Pending snapshots:".yellow());
+    } else {
+        println!(
+            "  {} {}",
+            "Pending snapshots:".yellow(),
+            report.pending_snapshots.len()
+        );
+        for snapshot in &report.pending_snapshots {
+            println!(
+                "    {} {} [{}]",
+                "⏳".yellow(),
+                snapshot.id,
+                snapshot.status.as_deref().unwrap_or("UNKNOWN")
+            );
+        }
+    }
+}
+
+pub(crate) fn is_pending(status: &str) -> bool {
+    !READY_SNAPSHOT_STATUSES.contains(&status.to_uppercase().as_str())
+        && !status.eq_ignore_ascii_case("FAILED")
+}
+
+/// The most recent snapshot by creation time; unreadable dates sort first.
+fn latest_snapshot<'a>(commits: &[&'a GetCommitResponse]) -> Option<&'a GetCommitResponse> {
+    commits
+        .iter()
+        .copied()
+        .max_by_key(|c| parse_api_timestamp(&c.created_date))
+}
+
+/// Parse the API's created_date, which may or may not carry a timezone.
+pub(crate) fn parse_api_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .map(|naive| naive.and_utc())
+}
+
+pub(crate) fn format_age(seconds: i64) -> String {
+    match seconds {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h {}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d {}h", s / 86400, (s % 86400) / 3600),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_api_timestamp_formats() {
+        assert!(parse_api_timestamp("2025-06-01T10:20:30Z").is_some());
+        assert!(parse_api_timestamp("2025-06-01T10:20:30.123456").is_some());
+        assert!(parse_api_timestamp("2025-06-01 10:20:30").is_some());
+        assert!(parse_api_timestamp("yesterday").is_none());
+    }
+
+    fn commit(id: &str, created_date: &str) -> GetCommitResponse {
+        GetCommitResponse {
+            id: id.to_string(),
+            name: id.to_string(),
+            status: "CREATED".to_string(),
+            dataset_id: None,
+            parent_id: None,
+            created_by: "guepard".to_string(),
+            created_date: created_date.to_string(),
+            snapshot_type: "MANUAL".to_string(),
+            is_ephemeral: false,
+            snapshot_comment: String::new(),
+            schema: None,
+        }
+    }
+
+    #[test]
+    fn test_latest_snapshot_compares_parsed_dates() {
+        // As strings "…T10:00:00+02:00" sorts after "…T09:30:00", though it is earlier
+        let offset = commit("a", "2025-06-01T10:00:00+02:00");
+        let naive = commit("b", "2025-06-01T09:30:00");
+        let unreadable = commit("c", "unknown");
+        let latest = latest_snapshot(&[&offset, &naive, &unreadable]).unwrap();
+        assert_eq!(latest.id, "b");
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(42), "42s");
+        assert_eq!(format_age(125), "2m");
+        assert_eq!(format_age(3 * 3600 + 15 * 60), "3h 15m");
+        assert_eq!(format_age(2 * 86400 + 5 * 3600), "2d 5h");
+    }
+
+    #[test]
+    fn test_is_pending() {
+        assert!(!is_pending("CREATED"));
+        assert!(!is_pending("completed"));
+        assert!(!is_pending("FAILED"));
+        assert!(is_pending("IN_PROGRESS"));
+        assert!(is_pending("PENDING"));
+    }
+}
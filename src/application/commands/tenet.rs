@@ -10,7 +10,7 @@ use tabled::Tabled;
 use crate::application::dto::tenet::{TenetDeployRequest, TenetDeployResponse};
 use crate::application::output::{print_json, print_row_or_json, OutputFormat};
 use crate::application::services::tenet;
-use crate::config::config::Config;
+use crate::config::config::{remove_tenet_endpoint, save_tenet_endpoint, Config, TenetEndpoint};
 use crate::domain::errors::tenet_error::TenetError;
 use crate::structure::{
     OutputArgs, TenetArgs, TenetCommand, TenetDeployCliArgs, TenetProxyAction, TenetProxyGetArgs,
@@ -156,6 +156,14 @@ async fn deploy(
         .or_else(|| d.client_host.clone())
         .or(resolved_upstream);
     let connect_host_str = connect_host.as_deref();
+    // Remembered for `guepard connect --via-tenet`; failing to cache is not fatal
+    let _ = save_tenet_endpoint(
+        &resp.job_id,
+        TenetEndpoint {
+            host: connect_host.clone(),
+            proxy_port: resp.proxy_port,
+        },
+    );
     let row = TenetDeployRow {
         job_id: resp.job_id.clone(),
         eval_id: resp.eval_id.clone(),
@@ -195,7 +203,11 @@ async fn lifecycle(
     let resp = match action {
         "start" => tenet::start_tenet(job_id, config).await?,
         "stop" => tenet::stop_tenet(job_id, config).await?,
-        "purge" => tenet::purge_tenet(job_id, config).await?,
+        "purge" => {
+            let resp = tenet::purge_tenet(job_id, config).await?;
+            let _ = remove_tenet_endpoint(job_id);
+            resp
+        }
         _ => unreachable!(),
     };
 
@@ -0,0 +1,465 @@
+use crate::application::commands::connection::{resolve_params, ConnectionParams, Engine};
+use crate::application::commands::exec::{run_mysql, run_postgres};
+use crate::application::dto::compute::ComputeState;
+use crate::application::duration::parse_duration;
+use crate::application::output::{print_json, OutputFormat};
+use crate::application::services::{clone, commit, compute, deploy};
+use crate::config::config::{resolve_deployment_id, Config};
+use crate::domain::errors::{
+    bookmark_error::BookmarkError, compute_error::ComputeError, deploy_error::DeployError,
+    wait_error::WaitError,
+};
+use crate::structure::WaitArgs;
+use anyhow::Result;
+use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+pub(crate) const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+pub(crate) const DEFAULT_WAIT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Longest a single readiness probe may take before it counts as failed.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Statuses that satisfy `--for ready` for deployments, clones and snapshots.
+const READY_STATUSES: [&str; 6] = [
+    "CREATED",
+    "COMPLETED",
+    "RUNNING",
+    "ACTIVE",
+    "READY",
+    "AVAILABLE",
+];
+
+/// Statuses after which waiting is pointless unless they are the target.
+const FAILED_STATUSES: [&str; 2] = ["FAILED", "ERROR"];
+
+/// Placeholder status while a resource is not listed yet.
+const NOT_FOUND: &str = "not found";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Resource {
+    Deployment,
+    Compute,
+    Snapshot,
+    Clone,
+}
+
+impl Resource {
+    fn parse(value: &str) -> Result<Self> {
+        match value {
+            "deployment" => Ok(Resource::Deployment),
+            "compute" => Ok(Resource::Compute),
+            "snapshot" => Ok(Resource::Snapshot),
+            "clone" => Ok(Resource::Clone),
+            other => Err(anyhow::anyhow!(
+                "Unknown resource '{}': use deployment, compute, snapshot or clone",
+                other
+            )),
+        }
+    }
+
+    fn default_target(self) -> &'static str {
+        match self {
+            Resource::Compute => "running",
+            _ => "ready",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Resource::Deployment => "deployment",
+            Resource::Compute => "compute",
+            Resource::Snapshot => "snapshot",
+            Resource::Clone => "clone",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Probe {
+    Tcp,
+    Sql,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum WaitOutcome {
+    Ready,
+    Failed,
+    Timeout,
+}
+
+/// What to wait for and how long.
+pub(crate) struct WaitSpec {
+    pub resource: Resource,
+    pub id: String,
+    /// Parent deployment, needed to look up snapshots.
+    pub deployment_id: Option<String>,
+    pub target: String,
+    pub timeout: Duration,
+    pub interval: Duration,
+    pub probe: Option<Probe>,
+}
+
+impl WaitSpec {
+    /// Wait for a deployment's or clone's compute to report running.
+    pub(crate) fn compute_running(id: &str, timeout: Duration) -> Self {
+        WaitSpec {
+            resource: Resource::Compute,
+            id: id.to_string(),
+            deployment_id: None,
+            target: Resource::Compute.default_target().to_string(),
+            timeout,
+            interval: DEFAULT_WAIT_INTERVAL,
+            probe: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct WaitReport {
+    pub resource: Resource,
+    pub id: String,
+    pub target: String,
+    pub outcome: WaitOutcome,
+    pub ready: bool,
+    pub status: Option<String>,
+    pub probe: Option<Probe>,
+    pub probe_ok: Option<bool>,
+    pub message: Option<String>,
+    pub elapsed_seconds: u64,
+}
+
+impl WaitReport {
+    /// The report as an error when the resource never became ready.
+    pub(crate) fn check(&self) -> Result<(), WaitError> {
+        let describe = || {
+            let mut detail = format!(
+                "{} {} not {} after {}s (status: {})",
+                self.resource.name(),
+                self.id,
+                self.target,
+                self.elapsed_seconds,
+                self.status.as_deref().unwrap_or("unknown")
+            );
+            if let Some(message) = &self.message {
+                detail.push_str(&format!("; {}", message));
+            }
+            detail
+        };
+        match self.outcome {
+            WaitOutcome::Ready => Ok(()),
+            WaitOutcome::Failed => Err(WaitError::Failed(describe())),
+            WaitOutcome::Timeout => Err(WaitError::Timeout(describe())),
+        }
+    }
+}
+
+/// Whether `status` is the state being waited for.
+fn reached(resource: Resource, target: &str, status: &str) -> bool {
+    if resource == Resource::Compute {
+        return ComputeState::parse(status) == ComputeState::parse(target);
+    }
+    if target.eq_ignore_ascii_case("ready") {
+        return READY_STATUSES.contains(&status.to_uppercase().as_str());
+    }
+    status.eq_ignore_ascii_case(target)
+}
+
+/// Whether `status` is a failure the resource will not recover from on its own.
+/// A compute reports failed health checks while it boots, so only the timeout
+/// ends a compute wait.
+fn gave_up(resource: Resource, target: &str, status: &str) -> bool {
+    resource != Resource::Compute
+        && FAILED_STATUSES.contains(&status.to_uppercase().as_str())
+        && !reached(resource, target, status)
+}
+
+fn is_session_error(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<DeployError>(),
+        Some(DeployError::SessionError(_))
+    ) || matches!(
+        error.downcast_ref::<ComputeError>(),
+        Some(ComputeError::SessionError(_))
+    ) || matches!(
+        error.downcast_ref::<BookmarkError>(),
+        Some(BookmarkError::SessionError(_))
+    )
+}
+
+/// The resource's current status, and for clones the parent deployment.
+async fn observe(spec: &WaitSpec, config: &Config) -> Result<(String, Option<String>)> {
+    match spec.resource {
+        Resource::Deployment => {
+            let deployment = deploy::get_deployment(&spec.id, config).await?;
+            Ok((deployment.status, Some(spec.id.clone())))
+        }
+        Resource::Compute => {
+            let (state, _) = compute::get_state(&spec.id, config).await?;
+            Ok((state.to_string(), Some(spec.id.clone())))
+        }
+        Resource::Snapshot => {
+            let deployment_id = spec.deployment_id.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("Waiting for a snapshot needs -x <deployment_id>")
+            })?;
+            let commits = commit::list_all_commits(deployment_id, config).await?;
+            let status = commits
+                .into_iter()
+                .find(|c| c.id == spec.id)
+                .map(|c| c.status)
+                .unwrap_or_else(|| NOT_FOUND.to_string());
+            Ok((status, None))
+        }
+        Resource::Clone => {
+            let clones = clone::list_all_clones(config).await?;
+            Ok(match clones.into_iter().find(|c| c.id == spec.id) {
+                Some(shadow) => (
+                    shadow.status.unwrap_or_else(|| "unknown".to_string()),
+                    shadow.deployment_parent,
+                ),
+                None => (NOT_FOUND.to_string(), None),
+            })
+        }
+    }
+}
+
+async fn probe_params(
+    spec: &WaitSpec,
+    parent: Option<&str>,
+    config: &Config,
+) -> Result<ConnectionParams> {
+    match spec.resource {
+        Resource::Clone => {
+            let parent = parent.ok_or_else(|| {
+                anyhow::anyhow!("Parent deployment of clone {} is unknown", spec.id)
+            })?;
+            resolve_params(parent, None, Some(&spec.id), config).await
+        }
+        _ => resolve_params(&spec.id, None, None, config).await,
+    }
+}
+
+/// Open a TCP connection to the endpoint, or run `SELECT 1` with `--probe sql`.
+async fn run_probe(probe: Probe, params: &ConnectionParams) -> Result<()> {
+    let attempt = async {
+        match (probe, params.engine) {
+            (Probe::Tcp, _) => {
+                tokio::net::TcpStream::connect((params.host.as_str(), params.port)).await?;
+                Ok(())
+            }
+            (Probe::Sql, Engine::PostgreSQL) => run_postgres(params, "SELECT 1").await.map(|_| ()),
+            (Probe::Sql, Engine::MySQL) => run_mysql(params, "SELECT 1").await.map(|_| ()),
+            (Probe::Sql, Engine::MongoDB) => Err(anyhow::anyhow!(
+                "--probe sql supports PostgreSQL and MySQL; use --probe tcp for MongoDB"
+            )),
+        }
+    };
+    tokio::time::timeout(PROBE_TIMEOUT, attempt)
+        .await
+        .map_err(|_| anyhow::anyhow!("probe timed out after {}s", PROBE_TIMEOUT.as_secs()))?
+}
+
+/// Poll until the resource reaches the target state (and the probe, if any,
+/// succeeds), it fails, or the timeout elapses. Only session errors and a
+/// timeout too long to schedule are returned as errors; everything else ends
+/// up in the report.
+pub(crate) async fn wait_for(
+    spec: &WaitSpec,
+    config: &Config,
+    show_spinner: bool,
+) -> Result<WaitReport> {
+    let started = Instant::now();
+    let deadline = started.checked_add(spec.timeout).ok_or_else(|| {
+        anyhow::anyhow!("Wait timeout of {}s is too long", spec.timeout.as_secs())
+    })?;
+    let spinner = if show_spinner {
+        let pb = ProgressBar::new_spinner();
+        pb.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.dim} {msg}")
+                .unwrap(),
+        );
+        pb.enable_steady_tick(Duration::from_millis(80));
+        Some(pb)
+    } else {
+        None
+    };
+
+    let mut status = None;
+    let mut message: Option<String>;
+    let mut probe_ok = spec.probe.map(|_| false);
+    let outcome = loop {
+        match observe(spec, config).await {
+            Ok((current, parent)) => {
+                message = None;
+                if gave_up(spec.resource, &spec.target, &current) {
+                    status = Some(current);
+                    break WaitOutcome::Failed;
+                }
+                if reached(spec.resource, &spec.target, &current) {
+                    let probed = match spec.probe {
+                        Some(probe) => {
+                            let result = match probe_params(spec, parent.as_deref(), config).await {
+                                Ok(params) => run_probe(probe, &params).await,
+                                Err(e) => Err(e),
+                            };
+                            if let Err(e) = &result {
+                                message = Some(format!("probe: {}", e));
+                            }
+                            probe_ok = Some(result.is_ok());
+                            result.is_ok()
+                        }
+                        None => true,
+                    };
+                    if probed {
+                        status = Some(current);
+                        break WaitOutcome::Ready;
+                    }
+                }
+                status = Some(current);
+            }
+            Err(e) if is_session_error(&e) => {
+                if let Some(pb) = &spinner {
+                    pb.finish_and_clear();
+                }
+                return Err(e);
+            }
+            Err(e) => message = Some(e.to_string()),
+        }
+
+        if let Some(pb) = &spinner {
+            pb.set_message(format!(
+                "Waiting for {} {} to be {}… status: {} ({}s)",
+                spec.resource.name(),
+                spec.id,
+                spec.target,
+                status.as_deref().unwrap_or("unknown"),
+                started.elapsed().as_secs()
+            ));
+        }
+        if Instant::now() + spec.interval > deadline {
+            break WaitOutcome::Timeout;
+        }
+        sleep(spec.interval).await;
+    };
+
+    if let Some(pb) = spinner {
+        pb.finish_with_message(match outcome {
+            WaitOutcome::Ready => format!(
+                "{} {} {} is {}",
+                "✓".green(),
+                spec.resource.name(),
+                spec.id,
+                spec.target
+            ),
+            WaitOutcome::Failed => {
+                format!("{} {} {} failed", "✗".red(), spec.resource.name(), spec.id)
+            }
+            WaitOutcome::Timeout => format!(
+                "{} {} {} is not {} yet",
+                "⚠".yellow(),
+                spec.resource.name(),
+                spec.id,
+                spec.target
+            ),
+        });
+    }
+
+    Ok(WaitReport {
+        resource: spec.resource,
+        id: spec.id.clone(),
+        target: spec.target.clone(),
+        outcome,
+        ready: outcome == WaitOutcome::Ready,
+        status,
+        probe: spec.probe,
+        probe_ok,
+        message,
+        elapsed_seconds: started.elapsed().as_secs(),
+    })
+}
+
+pub async fn wait(args: &WaitArgs, config: &Config, output_format: OutputFormat) -> Result<()> {
+    let resource = Resource::parse(&args.resource)?;
+    let (id, deployment_id) = match resource {
+        Resource::Deployment | Resource::Compute => {
+            (resolve_deployment_id(args.id.as_deref())?, None)
+        }
+        Resource::Snapshot => (
+            args.id
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Give the snapshot ID to wait for"))?,
+            Some(resolve_deployment_id(args.deployment_id.as_deref())?),
+        ),
+        Resource::Clone => (
+            args.id
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Give the clone ID to wait for"))?,
+            None,
+        ),
+    };
+
+    let target = args
+        .target
+        .clone()
+        .unwrap_or_else(|| resource.default_target().to_string());
+    if resource == Resource::Compute && ComputeState::parse(&target) == ComputeState::Unknown {
+        return Err(anyhow::anyhow!(
+            "Unknown compute state '{}': use pending, starting, running, stopping, stopped or failed",
+            target
+        ));
+    }
+    let probe = match args.probe.as_deref() {
+        None => None,
+        Some(_) if resource == Resource::Snapshot => {
+            return Err(anyhow::anyhow!("Snapshots have no endpoint to probe"))
+        }
+        Some("tcp") => Some(Probe::Tcp),
+        Some("sql") => Some(Probe::Sql),
+        Some(other) => return Err(anyhow::anyhow!("Unknown probe '{}': use tcp or sql", other)),
+    };
+
+    let spec = WaitSpec {
+        resource,
+        id,
+        deployment_id,
+        target,
+        timeout: parse_duration(&args.timeout)?,
+        interval: parse_duration(&args.interval)?.max(Duration::from_secs(1)),
+        probe,
+    };
+    let report = wait_for(&spec, config, output_format == OutputFormat::Table).await?;
+
+    if output_format == OutputFormat::Json {
+        print_json(&report);
+    } else if let Some(message) = report.message.as_deref().filter(|_| !report.ready) {
+        eprintln!("{} {}", "⚠️".yellow(), message);
+    }
+    report.check()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reached_and_gave_up() {
+        assert!(reached(Resource::Compute, "running", "enabled"));
+        assert!(!reached(Resource::Compute, "running", "starting"));
+        assert!(reached(Resource::Compute, "stopped", "stopped"));
+        assert!(reached(Resource::Deployment, "ready", "CREATED"));
+        assert!(!reached(Resource::Deployment, "ready", "INIT"));
+        assert!(reached(Resource::Snapshot, "pending", "PENDING"));
+        assert!(gave_up(Resource::Clone, "ready", "FAILED"));
+        assert!(!gave_up(Resource::Clone, "failed", "FAILED"));
+        assert!(!gave_up(Resource::Compute, "running", "failed"));
+        assert!(!gave_up(Resource::Snapshot, "ready", NOT_FOUND));
+    }
+}
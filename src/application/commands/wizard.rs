@@ -0,0 +1,432 @@
+use crate::application::commands::deploy::generate_password;
+use crate::application::dto::deploy::CreateDeploymentRequest;
+use crate::application::dto::manifest::{
+    Manifest, ManifestDeployment, DEFAULT_MANIFEST_FILE, DEFAULT_PASSWORD_ENV,
+};
+use crate::application::services::deploy;
+use crate::application::services::image::{self, ImageProvider};
+use crate::application::services::performance::{self, PerformanceProfile};
+use crate::config::config::Config;
+use anyhow::Result;
+use colored::Colorize;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Password, Select};
+use std::fs;
+use std::io::IsTerminal;
+use std::path::Path;
+
+const FALLBACK_PROVIDERS: [(&str, &str); 3] =
+    [("PostgreSQL", "16"), ("MySQL", "8.0"), ("MongoDB", "7.0")];
+const DEPLOYMENT_TYPES: [&str; 2] = ["REPOSITORY", "F2"];
+const DEFAULT_REGIONS: [&str; 3] = ["us-west-aws", "us-east-aws", "eu-west-aws"];
+const DEFAULT_PERFORMANCE_PROFILE: &str = "gp.g1.xsmall";
+const DEFAULT_REQUEST_FILE: &str = "deploy-request.json";
+const OTHER_OPTION: &str = "Other…";
+
+/// Answers collected by the deploy wizard, ready to be submitted.
+pub(crate) struct DeployAnswers {
+    pub deployment: ManifestDeployment,
+    pub password: String,
+    pub performance_profile_id: Option<String>,
+}
+
+impl DeployAnswers {
+    pub(crate) fn to_request(&self, performance_profile_id: String) -> CreateDeploymentRequest {
+        let d = &self.deployment;
+        CreateDeploymentRequest {
+            repository_name: d.name.clone(),
+            database_provider: d.provider.clone(),
+            database_version: d.version.clone(),
+            deployment_type: d.deployment_type.clone(),
+            region: d.region.clone(),
+            datacenter: d.datacenter.clone(),
+            database_username: d.username.clone().unwrap_or_else(|| "guepard".to_string()),
+            database_password: self.password.clone(),
+            performance_profile_id,
+            node_id: d.node_id.clone(),
+        }
+    }
+}
+
+/// Run the guided setup. Returns `None` when the user only saved the answers
+/// to a file or cancelled.
+pub(crate) async fn run(config: &Config) -> Result<Option<DeployAnswers>> {
+    if !std::io::stdin().is_terminal() {
+        return Err(anyhow::anyhow!(
+            "--interactive needs a terminal; pass the deploy flags instead (see 'guepard deploy --help')"
+        ));
+    }
+
+    println!("{} Welcome to Interactive Deployment! 🚀", "🐆".cyan());
+    println!(
+        "{} Use the arrow keys to choose, Enter to confirm.",
+        "💡".yellow()
+    );
+    println!();
+
+    let theme = ColorfulTheme::default();
+
+    // Live option lists; fall back to static choices if the API is unreachable
+    let images = image::list_image_providers(config)
+        .await
+        .unwrap_or_default();
+    let profiles = performance::list_performance_profiles(config)
+        .await
+        .unwrap_or_default();
+    let existing = deploy::list_deployments(config).await.unwrap_or_default();
+
+    let (provider, version) = select_image(&theme, &images)?;
+
+    let type_index = Select::with_theme(&theme)
+        .with_prompt("Deployment type")
+        .items(&DEPLOYMENT_TYPES)
+        .default(0)
+        .interact()?;
+    let deployment_type = DEPLOYMENT_TYPES[type_index].to_string();
+
+    let known_regions = known_values(existing.iter().map(|d| d.region.as_str()));
+    let region = select_or_enter(&theme, "Region", &known_regions)?;
+    let known_datacenters = known_values(existing.iter().map(|d| d.datacenter.as_str()));
+    let datacenter = select_or_enter(&theme, "Datacenter", &known_datacenters)?;
+
+    let existing_names: Vec<String> = existing.iter().map(|d| d.repository_name.clone()).collect();
+    let name: String = Input::with_theme(&theme)
+        .with_prompt("Repository name")
+        .validate_with(|input: &String| validate_repository_name(input, &existing_names))
+        .interact_text()?;
+
+    let username: String = Input::with_theme(&theme)
+        .with_prompt("Database username")
+        .default("guepard".to_string())
+        .interact_text()?;
+
+    let password = Password::with_theme(&theme)
+        .with_prompt("Database password (Enter to auto-generate)")
+        .with_confirmation("Confirm password", "Passwords don't match")
+        .allow_empty_password(true)
+        .interact()?;
+    let password = if password.is_empty() {
+        println!("{} A strong password will be generated.", "🔐".green());
+        generate_password()
+    } else {
+        password
+    };
+
+    let (performance_profile, performance_profile_id) =
+        select_profile(&theme, &profiles, &provider, &version)?;
+
+    let answers = DeployAnswers {
+        deployment: ManifestDeployment {
+            name,
+            provider,
+            version,
+            region,
+            datacenter,
+            deployment_type,
+            performance_profile: Some(performance_profile),
+            username: Some(username),
+            password_env: None,
+            node_id: None,
+            branches: Vec::new(),
+            tenets: Vec::new(),
+        },
+        password,
+        performance_profile_id,
+    };
+
+    review(&answers);
+
+    let actions = [
+        "Create deployment",
+        "Save as guepard.yaml manifest",
+        "Save as JSON request file",
+        "Cancel",
+    ];
+    loop {
+        let action = Select::with_theme(&theme)
+            .with_prompt("What next?")
+            .items(&actions)
+            .default(0)
+            .interact()?;
+        match action {
+            0 => return Ok(Some(answers)),
+            1 => save_answers(
+                &theme,
+                &answers,
+                DEFAULT_MANIFEST_FILE,
+                SaveFormat::Manifest,
+            )?,
+            2 => save_answers(&theme, &answers, DEFAULT_REQUEST_FILE, SaveFormat::Request)?,
+            _ => {
+                println!("{} Deployment cancelled.", "ℹ️".blue());
+                return Ok(None);
+            }
+        }
+    }
+}
+
+fn select_image(theme: &ColorfulTheme, images: &[ImageProvider]) -> Result<(String, String)> {
+    if images.is_empty() {
+        let labels: Vec<&str> = FALLBACK_PROVIDERS.iter().map(|(p, _)| *p).collect();
+        let index = Select::with_theme(theme)
+            .with_prompt("Database provider")
+            .items(&labels)
+            .default(0)
+            .interact()?;
+        let (provider, default_version) = FALLBACK_PROVIDERS[index];
+        let version: String = Input::with_theme(theme)
+            .with_prompt("Database version")
+            .default(default_version.to_string())
+            .interact_text()?;
+        return Ok((provider.to_string(), version));
+    }
+
+    let providers = image::provider_names(images);
+    let index = Select::with_theme(theme)
+        .with_prompt("Database provider")
+        .items(&providers)
+        .default(
+            providers
+                .iter()
+                .position(|p| *p == "PostgreSQL")
+                .unwrap_or(0),
+        )
+        .interact()?;
+    let provider = providers[index];
+
+    let versions: Vec<&ImageProvider> = images
+        .iter()
+        .filter(|i| i.is_active && i.catalog.database_provider == provider)
+        .collect();
+    let labels: Vec<String> = versions
+        .iter()
+        .map(|i| {
+            let mut label = i.catalog.database_version.clone();
+            if let Some(status) = &i.catalog.support_status {
+                label.push_str(&format!(" ({})", status.to_lowercase()));
+            }
+            label
+        })
+        .collect();
+    let default_version = image::default_version(images, provider);
+    let index = Select::with_theme(theme)
+        .with_prompt("Database version")
+        .items(&labels)
+        .default(
+            versions
+                .iter()
+                .position(|i| Some(i.catalog.database_version.as_str()) == default_version)
+                .unwrap_or(0),
+        )
+        .interact()?;
+
+    Ok((
+        provider.to_string(),
+        versions[index].catalog.database_version.clone(),
+    ))
+}
+
+/// Returns the chosen label and, when the profile list was available, its ID.
+fn select_profile(
+    theme: &ColorfulTheme,
+    profiles: &[PerformanceProfile],
+    provider: &str,
+    version: &str,
+) -> Result<(String, Option<String>)> {
+    let matching = profiles_for(profiles, provider, version);
+    if matching.is_empty() {
+        let label: String = Input::with_theme(theme)
+            .with_prompt("Performance profile")
+            .default(DEFAULT_PERFORMANCE_PROFILE.to_string())
+            .interact_text()?;
+        return Ok((label, None));
+    }
+
+    let labels: Vec<String> = matching
+        .iter()
+        .map(|p| {
+            format!(
+                "{} ({} CPU, {} MB){}",
+                p.label_name,
+                p.min_cpu,
+                p.min_memory,
+                if p.is_default { " · default" } else { "" }
+            )
+        })
+        .collect();
+    let index = Select::with_theme(theme)
+        .with_prompt("Performance profile")
+        .items(&labels)
+        .default(matching.iter().position(|p| p.is_default).unwrap_or(0))
+        .interact()?;
+    Ok((
+        matching[index].label_name.clone(),
+        Some(matching[index].id.clone()),
+    ))
+}
+
+/// Active profiles offered for a provider/version pair.
+pub(crate) fn profiles_for<'a>(
+    profiles: &'a [PerformanceProfile],
+    provider: &str,
+    version: &str,
+) -> Vec<&'a PerformanceProfile> {
+    profiles
+        .iter()
+        .filter(|p| {
+            p.is_active
+                && p.database_provider.eq_ignore_ascii_case(provider)
+                && p.database_version == version
+        })
+        .collect()
+}
+
+fn select_or_enter(theme: &ColorfulTheme, prompt: &str, options: &[String]) -> Result<String> {
+    let mut items: Vec<&str> = options.iter().map(String::as_str).collect();
+    items.push(OTHER_OPTION);
+    let index = Select::with_theme(theme)
+        .with_prompt(prompt)
+        .items(&items)
+        .default(0)
+        .interact()?;
+    if items[index] != OTHER_OPTION {
+        return Ok(items[index].to_string());
+    }
+    Ok(Input::with_theme(theme)
+        .with_prompt(prompt)
+        .validate_with(|input: &String| {
+            if input.trim().is_empty() {
+                Err("must not be empty")
+            } else {
+                Ok(())
+            }
+        })
+        .interact_text()?)
+}
+
+/// Regions/datacenters already in use by the account, followed by the defaults.
+fn known_values<'a>(used: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for value in used.chain(DEFAULT_REGIONS) {
+        if !value.is_empty() && !values.iter().any(|v| v == value) {
+            values.push(value.to_string());
+        }
+    }
+    values
+}
+
+pub(crate) fn validate_repository_name(name: &str, existing: &[String]) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Repository name is required".to_string());
+    }
+    // The API's own example name is "guepard_demo-1"; it documents no length limit
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Use letters, digits, hyphens and underscores only".to_string());
+    }
+    if existing.iter().any(|e| e == name) {
+        return Err(format!("A deployment named '{}' already exists", name));
+    }
+    Ok(())
+}
+
+fn review(answers: &DeployAnswers) {
+    let d = &answers.deployment;
+    println!();
+    println!("{} Review", "📋".blue());
+    println!("  {} {} {}", "Database:".yellow(), d.provider, d.version);
+    println!("  {} {}", "Type:".yellow(), d.deployment_type);
+    println!("  {} {}", "Region:".yellow(), d.region);
+    println!("  {} {}", "Datacenter:".yellow(), d.datacenter);
+    println!("  {} {}", "Repository:".yellow(), d.name);
+    println!(
+        "  {} {}",
+        "Username:".yellow(),
+        d.username.as_deref().unwrap_or("guepard")
+    );
+    println!("  {} {}", "Password:".yellow(), "********".dimmed());
+    println!(
+        "  {} {}",
+        "Performance:".yellow(),
+        d.performance_profile
+            .as_deref()
+            .unwrap_or(DEFAULT_PERFORMANCE_PROFILE)
+    );
+    println!();
+}
+
+enum SaveFormat {
+    Manifest,
+    Request,
+}
+
+/// Save the answers without the password; it is referenced by environment variable.
+fn save_answers(
+    theme: &ColorfulTheme,
+    answers: &DeployAnswers,
+    default_path: &str,
+    format: SaveFormat,
+) -> Result<()> {
+    let path: String = Input::with_theme(theme)
+        .with_prompt("File")
+        .default(default_path.to_string())
+        .interact_text()?;
+    if Path::new(&path).exists()
+        && !Confirm::with_theme(theme)
+            .with_prompt(format!("{} exists. Overwrite?", path))
+            .default(false)
+            .interact()?
+    {
+        return Ok(());
+    }
+    let password_env: String = Input::with_theme(theme)
+        .with_prompt("Environment variable for the password")
+        .default(DEFAULT_PASSWORD_ENV.to_string())
+        .interact_text()?;
+
+    let mut deployment = answers.deployment.clone();
+    deployment.password_env = Some(password_env.clone());
+    let content = match format {
+        SaveFormat::Manifest => serde_yaml::to_string(&Manifest {
+            version: 1,
+            deployments: vec![deployment],
+        })?,
+        SaveFormat::Request => serde_json::to_string_pretty(&deployment)? + "\n",
+    };
+    fs::write(&path, content)?;
+
+    println!("{} Saved {}", "✅".green(), path.cyan());
+    println!(
+        "{} The password is not stored; export {} before using this file.",
+        "💡".yellow(),
+        password_env
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_repository_name() {
+        let existing = vec!["taken".to_string()];
+        assert!(validate_repository_name("my-db-1", &existing).is_ok());
+        assert!(validate_repository_name("", &existing).is_err());
+        assert!(validate_repository_name("guepard_demo-1", &existing).is_ok());
+        assert!(validate_repository_name("my db", &existing).is_err());
+        assert!(validate_repository_name("taken", &existing).is_err());
+        assert!(validate_repository_name(&"a".repeat(64), &existing).is_ok());
+    }
+
+    #[test]
+    fn test_known_values_keeps_used_first_without_duplicates() {
+        let values = known_values(["eu-west-aws", "", "ap-south"].into_iter());
+        assert_eq!(
+            values,
+            vec!["eu-west-aws", "ap-south", "us-west-aws", "us-east-aws"]
+        );
+    }
+}
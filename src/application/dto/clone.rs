@@ -38,3 +38,63 @@ pub struct CreateCloneResponse {
 }
 
 pub type ListClonesResponse = Vec<CreateCloneResponse>;
+
+/// A shadow as returned by the account-wide `GET /deploy/shadow` listing.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShadowSummary {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default, alias = "deployment_id")]
+    pub deployment_parent: Option<String>,
+    #[serde(default, alias = "snapshot_parent")]
+    pub snapshot_id: Option<String>,
+    #[serde(default)]
+    pub branch_id: Option<String>,
+    #[serde(default, alias = "created_at", alias = "created_date")]
+    pub created: Option<String>,
+}
+
+/// `GET /deploy/shadow` may wrap the list as `{ "shadows": [...], "total": n }`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ListAllShadowsResponse {
+    Wrapped { shadows: Vec<ShadowSummary> },
+    Plain(Vec<ShadowSummary>),
+}
+
+impl ListAllShadowsResponse {
+    pub fn into_shadows(self) -> Vec<ShadowSummary> {
+        match self {
+            ListAllShadowsResponse::Wrapped { shadows } => shadows,
+            ListAllShadowsResponse::Plain(shadows) => shadows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wrapped_and_plain_shadow_lists() {
+        let wrapped = r#"{"shadows":[{"id":"shadow_123","name":"Testing Shadow",
+            "deployment_id":"deploy_789","created_at":"2024-01-15T10:30:00Z",
+            "status":"active","snapshot_id":"snapshot_456","branch_id":"branch_789"}],"total":1}"#;
+        let shadows = serde_json::from_str::<ListAllShadowsResponse>(wrapped)
+            .unwrap()
+            .into_shadows();
+        assert_eq!(shadows[0].deployment_parent.as_deref(), Some("deploy_789"));
+        assert_eq!(shadows[0].created.as_deref(), Some("2024-01-15T10:30:00Z"));
+
+        let plain = r#"[{"id":"s1","deployment_parent":"d1","snapshot_parent":"snap1"}]"#;
+        let shadows = serde_json::from_str::<ListAllShadowsResponse>(plain)
+            .unwrap()
+            .into_shadows();
+        assert_eq!(shadows[0].snapshot_id.as_deref(), Some("snap1"));
+    }
+}
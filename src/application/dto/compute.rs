@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Deserialize)]
 pub struct ListComputeResponse {
@@ -23,8 +24,150 @@ pub struct StatusErrorResponse {
     pub message: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct ComputeStatusResponse {
     pub message: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(default, alias = "uptime_seconds")]
+    pub uptime: Option<u64>,
+}
+
+impl ComputeStatusResponse {
+    /// A 200 without a status field comes from older APIs and means running.
+    pub fn state(&self) -> ComputeState {
+        self.status
+            .as_deref()
+            .map(ComputeState::parse)
+            .unwrap_or(ComputeState::Running)
+    }
+}
+
+/// Lifecycle of a compute instance. Each state has its own exit code so
+/// scripts can branch on `guepard compute status` without parsing output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComputeState {
+    Pending,
+    Starting,
+    Running,
+    Stopping,
+    Stopped,
+    Failed,
+    Unknown,
+}
+
+impl ComputeState {
+    /// Parse a status reported by the API, accepting the older synonyms
+    /// ("enabled", "healthy", "disabled", ...).
+    pub fn parse(status: &str) -> Self {
+        let normalized = status.trim().to_lowercase().replace([' ', '-'], "_");
+        match normalized.as_str() {
+            "pending" | "init" | "initializing" | "provisioning" | "creating" | "queued" => {
+                ComputeState::Pending
+            }
+            "starting" | "booting" | "restarting" | "mounting" => ComputeState::Starting,
+            "running" | "enabled" | "healthy" | "ready" | "active" | "started" => {
+                ComputeState::Running
+            }
+            "stopping" | "shutting_down" | "unmounting" => ComputeState::Stopping,
+            "stopped" | "disabled" | "not_running" | "inactive" | "terminated" => {
+                ComputeState::Stopped
+            }
+            "failed" | "error" | "unhealthy" | "not_healthy" | "crashed" => ComputeState::Failed,
+            _ => ComputeState::Unknown,
+        }
+    }
+
+    /// Infer the state from the message of a "not healthy" response, which
+    /// carries no status field. Anything unrecognised counts as failed.
+    pub fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        let phrases = [
+            ("not running", ComputeState::Stopped),
+            ("stopped", ComputeState::Stopped),
+            ("stopping", ComputeState::Stopping),
+            ("starting", ComputeState::Starting),
+            ("pending", ComputeState::Pending),
+            ("provisioning", ComputeState::Pending),
+        ];
+        phrases
+            .iter()
+            .find(|(phrase, _)| message.contains(phrase))
+            .map(|(_, state)| *state)
+            .unwrap_or(ComputeState::Failed)
+    }
+
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ComputeState::Running => 0,
+            ComputeState::Pending => 20,
+            ComputeState::Starting => 21,
+            ComputeState::Stopping => 22,
+            ComputeState::Stopped => 23,
+            ComputeState::Failed => 24,
+            ComputeState::Unknown => 25,
+        }
+    }
+}
+
+impl fmt::Display for ComputeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            ComputeState::Pending => "pending",
+            ComputeState::Starting => "starting",
+            ComputeState::Running => "running",
+            ComputeState::Stopping => "stopping",
+            ComputeState::Stopped => "stopped",
+            ComputeState::Failed => "failed",
+            ComputeState::Unknown => "unknown",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_state_parse() {
+        assert_eq!(ComputeState::parse("enabled"), ComputeState::Running);
+        assert_eq!(ComputeState::parse("Running"), ComputeState::Running);
+        assert_eq!(ComputeState::parse("shutting-down"), ComputeState::Stopping);
+        assert_eq!(ComputeState::parse("NOT RUNNING"), ComputeState::Stopped);
+        assert_eq!(ComputeState::parse("provisioning"), ComputeState::Pending);
+        assert_eq!(ComputeState::parse("weird"), ComputeState::Unknown);
+        assert_eq!(
+            ComputeState::from_message("Compute is not running"),
+            ComputeState::Stopped
+        );
+        assert_eq!(
+            ComputeState::from_message("Health check failed"),
+            ComputeState::Failed
+        );
+        assert_eq!(
+            ComputeStatusResponse::default().state(),
+            ComputeState::Running
+        );
+    }
+
+    #[test]
+    fn test_compute_state_exit_codes_are_distinct() {
+        let states = [
+            ComputeState::Pending,
+            ComputeState::Starting,
+            ComputeState::Running,
+            ComputeState::Stopping,
+            ComputeState::Stopped,
+            ComputeState::Failed,
+            ComputeState::Unknown,
+        ];
+        let mut codes: Vec<i32> = states.iter().map(|s| s.exit_code()).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), states.len());
+        assert_eq!(ComputeState::Running.exit_code(), 0);
+    }
 }
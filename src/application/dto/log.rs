@@ -1,3 +1,6 @@
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -8,13 +11,21 @@ pub struct LogResponse {
 
 #[derive(Debug, Serialize)]
 pub struct LogLine {
+    /// RFC 3339 in UTC, so lines from any engine sort and compare as strings.
     pub timestamp: Option<String>,
     pub level: LogLevel,
+    /// Process ID (PostgreSQL, MongoDB) or thread ID (MySQL), when logged.
+    pub pid: Option<u32>,
+    /// The line without its timestamp, pid and severity prefix.
+    pub message: String,
     pub content: String,
     pub source: LogSource,
+    /// Shown only as context around a `--grep`/`--level` match.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub context: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum LogLevel {
     Info,
     Warning,
@@ -23,71 +34,473 @@ pub enum LogLevel {
     Trace,
 }
 
+impl LogLevel {
+    /// Parse a level name as given to `--level` ("warn", "warning", "err", ...).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" | "log" | "notice" | "note" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warning),
+            "error" | "err" | "fatal" | "panic" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// Rank from least (trace) to most (error) severe.
+    pub fn severity(self) -> u8 {
+        match self {
+            LogLevel::Trace => 0,
+            LogLevel::Debug => 1,
+            LogLevel::Info => 2,
+            LogLevel::Warning => 3,
+            LogLevel::Error => 4,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub enum LogSource {
     Stdout,
     Stderr,
 }
 
-impl LogResponse {
-    pub fn parse_logs(&self) -> Vec<LogLine> {
-        let mut lines = Vec::new();
+/// What a parser could read from one line. A missing level means the line
+/// continues the previous entry (e.g. a PostgreSQL DETAIL line) and inherits
+/// its level.
+#[derive(Debug, Default, PartialEq)]
+pub struct ParsedLine {
+    pub timestamp: Option<DateTime<Utc>>,
+    pub level: Option<LogLevel>,
+    pub pid: Option<u32>,
+    pub message: String,
+}
+
+/// Reads the log format of one database engine.
+pub trait LogParser {
+    /// Parse a line in the engine's format, or None if it is not one.
+    fn parse(&self, line: &str) -> Option<ParsedLine>;
+}
 
-        // Parse stdout logs
-        for line in self.stdout_logs.lines() {
-            if !line.trim().is_empty() {
-                lines.push(LogLine {
-                    timestamp: Self::extract_timestamp(line),
-                    level: Self::extract_level(line),
-                    content: line.to_string(),
-                    source: LogSource::Stdout,
-                });
+lazy_static! {
+    // "2026-01-15 10:20:30.123 UTC [42] LOG:  message", with any log_line_prefix
+    // that starts with %m or %t and contains [%p]
+    static ref POSTGRES_TIMESTAMP: Regex = Regex::new(
+        r"^(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}(?:\.\d+)?)(?: ([A-Z]{2,5}|[+-]\d{2}(?::?\d{2})?))?"
+    )
+    .unwrap();
+    static ref POSTGRES_PID: Regex = Regex::new(r"\[(\d+)\]").unwrap();
+    static ref POSTGRES_SEVERITY: Regex = Regex::new(
+        r"\b(DEBUG[1-5]|INFO|NOTICE|WARNING|ERROR|LOG|FATAL|PANIC|DETAIL|HINT|CONTEXT|STATEMENT|QUERY|LOCATION):\s+"
+    )
+    .unwrap();
+    // "2026-01-15T10:20:30.123456Z 8 [Warning] [MY-010068] [Server] message" (8.0),
+    // "2026-01-15T10:20:30.123456Z 0 [Note] message" (5.7) and the docker
+    // entrypoint's "2026-01-15 10:20:30+00:00 [Note] [Entrypoint]: message"
+    static ref MYSQL_LINE: Regex = Regex::new(
+        r"^(\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:?\d{2})?)\s+(?:(\d+)\s+)?\[(System|ERROR|Error|Warning|Warn|Note|Info)\]\s*(?:\[MY-\d+\]\s*)?(?:\[[A-Za-z]+\]\s*)?:?\s*(.*)$"
+    )
+    .unwrap();
+    static ref GENERIC_TIMESTAMP: Regex = Regex::new(
+        r"^\[?(\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2}| UTC)?)\]?\s*"
+    )
+    .unwrap();
+    // Severity words standing alone, so "ERROR_COUNT=0" is not an error
+    static ref GENERIC_SEVERITY: Regex = Regex::new(
+        r"(?:^|[\s\[(])(FATAL|PANIC|ERROR|WARNING|WARN|NOTICE|INFO|LOG|DEBUG|TRACE)(?:[\]):]|\s|$)"
+    )
+    .unwrap();
+}
+
+/// Parse "2026-01-15 10:20:30.123" with an optional zone ("UTC", "+02", "+02:00").
+/// Zone abbreviations other than UTC/GMT are ambiguous and read as UTC.
+fn parse_timestamp(value: &str, zone: Option<&str>) -> Option<DateTime<Utc>> {
+    let value = value.replace(',', ".");
+    match zone {
+        Some(zone) if zone.starts_with('+') || zone.starts_with('-') => {
+            let offset = if zone.len() == 3 {
+                format!("{}:00", zone)
+            } else if !zone.contains(':') {
+                format!("{}:{}", &zone[..3], &zone[3..])
+            } else {
+                zone.to_string()
+            };
+            DateTime::parse_from_rfc3339(&format!("{}{}", value.replace(' ', "T"), offset))
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc))
+        }
+        _ => {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(&value.replace(' ', "T")) {
+                return Some(dt.with_timezone(&Utc));
             }
+            ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+                .iter()
+                .find_map(|fmt| NaiveDateTime::parse_from_str(&value, fmt).ok())
+                .map(|naive| naive.and_utc())
         }
+    }
+}
 
-        // Parse stderr logs
-        for line in self.stderr_logs.lines() {
-            if !line.trim().is_empty() {
-                lines.push(LogLine {
-                    timestamp: Self::extract_timestamp(line),
-                    level: Self::extract_level(line),
-                    content: line.to_string(),
-                    source: LogSource::Stderr,
-                });
+/// Split an ISO timestamp with a trailing "Z", offset or " UTC" for `parse_timestamp`.
+fn parse_iso_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Some(naive) = value.strip_suffix(" UTC") {
+        return parse_timestamp(naive, None);
+    }
+    if let Some(naive) = value.strip_suffix('Z') {
+        return parse_timestamp(naive, None);
+    }
+    // An offset starts at the last '+' or '-' after the time
+    match value.rfind(['+', '-']).filter(|&i| i > 10) {
+        Some(i) => parse_timestamp(&value[..i], Some(&value[i..])),
+        None => parse_timestamp(value, None),
+    }
+}
+
+pub struct PostgresLogParser;
+
+impl LogParser for PostgresLogParser {
+    fn parse(&self, line: &str) -> Option<ParsedLine> {
+        let timestamp = POSTGRES_TIMESTAMP.captures(line)?;
+        let rest = &line[timestamp.get(0)?.end()..];
+        let severity = POSTGRES_SEVERITY.captures(rest)?;
+        let prefix = &rest[..severity.get(0)?.start()];
+        let level = match &severity[1] {
+            "PANIC" | "FATAL" | "ERROR" => Some(LogLevel::Error),
+            "WARNING" => Some(LogLevel::Warning),
+            "LOG" | "INFO" | "NOTICE" => Some(LogLevel::Info),
+            "DEBUG1" | "DEBUG2" => Some(LogLevel::Debug),
+            s if s.starts_with("DEBUG") => Some(LogLevel::Trace),
+            // DETAIL, HINT, CONTEXT, STATEMENT, ... belong to the line above
+            _ => None,
+        };
+        Some(ParsedLine {
+            timestamp: parse_timestamp(&timestamp[1], timestamp.get(2).map(|m| m.as_str())),
+            level,
+            pid: POSTGRES_PID
+                .captures(prefix)
+                .and_then(|c| c[1].parse().ok()),
+            message: rest[severity.get(0)?.end()..].trim_end().to_string(),
+        })
+    }
+}
+
+pub struct MySqlLogParser;
+
+impl LogParser for MySqlLogParser {
+    fn parse(&self, line: &str) -> Option<ParsedLine> {
+        let captures = MYSQL_LINE.captures(line)?;
+        let level = match &captures[3] {
+            "ERROR" | "Error" => LogLevel::Error,
+            "Warning" | "Warn" => LogLevel::Warning,
+            _ => LogLevel::Info,
+        };
+        Some(ParsedLine {
+            timestamp: parse_iso_timestamp(&captures[1]),
+            level: Some(level),
+            pid: captures.get(2).and_then(|m| m.as_str().parse().ok()),
+            message: captures[4].trim_end().to_string(),
+        })
+    }
+}
+
+/// MongoDB 4.4+ structured logs: one JSON document per line.
+pub struct MongoLogParser;
+
+impl LogParser for MongoLogParser {
+    fn parse(&self, line: &str) -> Option<ParsedLine> {
+        let line = line.trim();
+        if !line.starts_with('{') {
+            return None;
+        }
+        let entry: serde_json::Value = serde_json::from_str(line).ok()?;
+        let severity = entry.get("s")?.as_str()?;
+        let level = match severity {
+            "F" | "E" => LogLevel::Error,
+            "W" => LogLevel::Warning,
+            "I" => LogLevel::Info,
+            "D1" | "D2" | "D" => LogLevel::Debug,
+            _ => LogLevel::Trace,
+        };
+        let timestamp = entry
+            .pointer("/t/$date")
+            .and_then(|t| t.as_str())
+            .and_then(parse_iso_timestamp);
+        let attr = entry.get("attr");
+        let pid = attr
+            .and_then(|a| a.get("pid"))
+            .and_then(|p| p.as_u64())
+            .and_then(|p| u32::try_from(p).ok());
+        let mut message = entry
+            .get("msg")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default()
+            .to_string();
+        if let Some(attr) = attr {
+            message.push(' ');
+            message.push_str(&attr.to_string());
+        }
+        Some(ParsedLine {
+            timestamp,
+            level: Some(level),
+            pid,
+            message,
+        })
+    }
+}
+
+/// Fallback for lines no engine parser understands: an ISO timestamp at the
+/// start and a standalone severity word.
+pub struct GenericLogParser;
+
+impl LogParser for GenericLogParser {
+    fn parse(&self, line: &str) -> Option<ParsedLine> {
+        let (timestamp, rest) = match GENERIC_TIMESTAMP.captures(line) {
+            Some(c) => (parse_iso_timestamp(&c[1]), &line[c.get(0)?.end()..]),
+            None => (None, line),
+        };
+        let level = GENERIC_SEVERITY.captures(rest).map(|c| match &c[1] {
+            "FATAL" | "PANIC" | "ERROR" => LogLevel::Error,
+            "WARNING" | "WARN" => LogLevel::Warning,
+            "DEBUG" => LogLevel::Debug,
+            "TRACE" => LogLevel::Trace,
+            _ => LogLevel::Info,
+        });
+        // No severity word: the line continues the entry before it
+        Some(ParsedLine {
+            timestamp,
+            level,
+            pid: None,
+            message: rest.trim_end().to_string(),
+        })
+    }
+}
+
+/// Parsers to try, most specific first, for a deployment's `database_provider`.
+/// Unknown providers try every engine format.
+pub fn parsers_for(database_provider: Option<&str>) -> Vec<Box<dyn LogParser>> {
+    let provider = database_provider.unwrap_or_default().to_lowercase();
+    let mut parsers: Vec<Box<dyn LogParser>> = if provider.contains("postgres") {
+        vec![Box::new(PostgresLogParser)]
+    } else if provider.contains("mysql") || provider.contains("mariadb") {
+        vec![Box::new(MySqlLogParser)]
+    } else if provider.contains("mongo") {
+        vec![Box::new(MongoLogParser)]
+    } else {
+        vec![
+            Box::new(PostgresLogParser),
+            Box::new(MySqlLogParser),
+            Box::new(MongoLogParser),
+        ]
+    };
+    parsers.push(Box::new(GenericLogParser));
+    parsers
+}
+
+impl LogResponse {
+    /// Parse with every known format; see `parse_logs_for`.
+    pub fn parse_logs(&self) -> Vec<LogLine> {
+        self.parse_logs_for(None)
+    }
+
+    /// Parse both streams with the parsers for `database_provider`, sorted by
+    /// timestamp. Lines without a timestamp keep their place after the line
+    /// they follow.
+    pub fn parse_logs_for(&self, database_provider: Option<&str>) -> Vec<LogLine> {
+        let parsers = parsers_for(database_provider);
+        let mut lines = Vec::new();
+        for (text, source) in [
+            (&self.stdout_logs, LogSource::Stdout),
+            (&self.stderr_logs, LogSource::Stderr),
+        ] {
+            let mut previous: Option<(Option<String>, LogLevel)> = None;
+            for line in text.lines().filter(|l| !l.trim().is_empty()) {
+                let parsed = parsers
+                    .iter()
+                    .find_map(|p| p.parse(line))
+                    .unwrap_or_default();
+                let timestamp = parsed
+                    .timestamp
+                    .map(|t| t.to_rfc3339_opts(SecondsFormat::Millis, true));
+                let level = parsed
+                    .level
+                    .or(previous.as_ref().map(|(_, level)| *level))
+                    .unwrap_or(LogLevel::Info);
+                // Continuation lines sort with the entry they belong to
+                let sort_key = timestamp
+                    .clone()
+                    .or_else(|| previous.as_ref().and_then(|(ts, _)| ts.clone()));
+                previous = Some((sort_key.clone(), level));
+                lines.push((
+                    sort_key,
+                    LogLine {
+                        timestamp,
+                        level,
+                        pid: parsed.pid,
+                        message: parsed.message,
+                        content: line.to_string(),
+                        source: match source {
+                            LogSource::Stdout => LogSource::Stdout,
+                            LogSource::Stderr => LogSource::Stderr,
+                        },
+                        context: false,
+                    },
+                ));
             }
         }
 
-        lines.sort_by(|a, b| match (&a.timestamp, &b.timestamp) {
+        // Stable, so lines sharing a timestamp keep their order
+        lines.sort_by(|(a, _), (b, _)| match (a, b) {
             (Some(ts_a), Some(ts_b)) => ts_a.cmp(ts_b),
             (Some(_), None) => std::cmp::Ordering::Less,
             (None, Some(_)) => std::cmp::Ordering::Greater,
             (None, None) => std::cmp::Ordering::Equal,
         });
+        lines.into_iter().map(|(_, line)| line).collect()
+    }
+}
 
-        lines
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postgres_lines() {
+        let parsed = PostgresLogParser
+            .parse("2026-01-15 10:20:30.123 UTC [42] LOG:  database system is ready to accept connections")
+            .unwrap();
+        assert_eq!(
+            parsed
+                .timestamp
+                .unwrap()
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2026-01-15T10:20:30.123Z"
+        );
+        assert_eq!(parsed.level, Some(LogLevel::Info));
+        assert_eq!(parsed.pid, Some(42));
+        assert_eq!(
+            parsed.message,
+            "database system is ready to accept connections"
+        );
+
+        let parsed = PostgresLogParser
+            .parse("2026-03-02 08:00:01 CET [1187]: [3-1] user=app,db=shop ERROR:  relation \"orders\" does not exist at character 15")
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Error));
+        assert_eq!(parsed.pid, Some(1187));
+        assert!(parsed.message.starts_with("relation \"orders\""));
+
+        let parsed = PostgresLogParser
+            .parse("2026-01-15 10:20:31.001 +02 [42] STATEMENT:  SELECT * FROM orders")
+            .unwrap();
+        assert_eq!(parsed.level, None);
+        assert_eq!(
+            parsed
+                .timestamp
+                .unwrap()
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2026-01-15T08:20:31.001Z"
+        );
+
+        assert!(PostgresLogParser
+            .parse("PostgreSQL init process complete; ready for start up.")
+            .is_none());
     }
 
-    fn extract_timestamp(line: &str) -> Option<String> {
-        // Look for PostgreSQL timestamp format: "2025-10-08 08:52:16.178 UTC"
-        if let Some(start) = line.find("2025-") {
-            if let Some(end) = line[start..].find(" UTC") {
-                return Some(line[start..start + end + 4].to_string());
-            }
-        }
-        None
-    }
-
-    fn extract_level(line: &str) -> LogLevel {
-        if line.contains("ERROR") || line.contains("FATAL") {
-            LogLevel::Error
-        } else if line.contains("WARNING") || line.contains("WARN") {
-            LogLevel::Warning
-        } else if line.contains("DEBUG") {
-            LogLevel::Debug
-        } else if line.contains("TRACE") {
-            LogLevel::Trace
-        } else {
-            LogLevel::Info
-        }
+    #[test]
+    fn test_mysql_lines() {
+        let parsed = MySqlLogParser
+            .parse("2026-01-15T10:20:30.123456Z 0 [System] [MY-010116] [Server] /usr/sbin/mysqld (mysqld 8.0.36) starting as process 1")
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Info));
+        assert_eq!(parsed.pid, Some(0));
+        assert!(parsed.message.starts_with("/usr/sbin/mysqld"));
+
+        let parsed = MySqlLogParser
+            .parse("2026-01-15T10:21:00.000001Z 8 [Warning] [MY-013360] [Server] Plugin sha256_password reported: 'sha256_password' is deprecated")
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Warning));
+        assert_eq!(parsed.pid, Some(8));
+
+        let parsed = MySqlLogParser
+            .parse("2026-01-15 10:19:58+00:00 [Note] [Entrypoint]: Entrypoint script for MySQL Server 8.0.36-1.el8 started.")
+            .unwrap();
+        assert_eq!(parsed.pid, None);
+        assert_eq!(
+            parsed.message,
+            "Entrypoint script for MySQL Server 8.0.36-1.el8 started."
+        );
+        assert!(parsed.timestamp.is_some());
+
+        let parsed = MySqlLogParser
+            .parse("2026-01-15T10:22:00.5Z 12 [ERROR] [MY-010584] [Repl] Slave SQL: Error 'Duplicate entry'")
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn test_mongo_lines() {
+        let parsed = MongoLogParser
+            .parse(r#"{"t":{"$date":"2026-01-15T10:20:30.123+00:00"},"s":"I","c":"CONTROL","id":4615611,"ctx":"initandlisten","msg":"MongoDB starting","attr":{"pid":1,"port":27017}}"#)
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Info));
+        assert_eq!(parsed.pid, Some(1));
+        assert!(parsed.message.starts_with("MongoDB starting {"));
+        assert_eq!(
+            parsed
+                .timestamp
+                .unwrap()
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2026-01-15T10:20:30.123Z"
+        );
+
+        let parsed = MongoLogParser
+            .parse(r#"{"t":{"$date":"2026-01-15T12:00:00.000+02:00"},"s":"W","c":"NETWORK","id":1,"ctx":"conn","msg":"Slow query"}"#)
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Warning));
+        assert_eq!(parsed.message, "Slow query");
+        assert_eq!(
+            parsed
+                .timestamp
+                .unwrap()
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            "2026-01-15T10:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn test_generic_severity_needs_a_standalone_word() {
+        let parsed = GenericLogParser.parse("stats: ERROR_COUNT=0").unwrap();
+        assert_eq!(parsed.level, None);
+        let parsed = GenericLogParser
+            .parse("2026-01-15T10:20:30Z [ERROR] disk full")
+            .unwrap();
+        assert_eq!(parsed.level, Some(LogLevel::Error));
+        assert!(parsed.timestamp.is_some());
+    }
+
+    #[test]
+    fn test_parse_logs_sorts_and_inherits_levels() {
+        let response = LogResponse {
+            stdout_logs: "2026-01-15 10:20:32.000 UTC [7] LOG:  checkpoint complete\n".to_string(),
+            stderr_logs: [
+                "2026-01-15 10:20:31.000 UTC [9] ERROR:  syntax error at or near \"SELEC\"",
+                "2026-01-15 10:20:31.000 UTC [9] STATEMENT:  SELEC 1",
+                "\tFROM accounts",
+            ]
+            .join("\n"),
+        };
+        let lines = response.parse_logs_for(Some("PostgreSQL"));
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0].level, LogLevel::Error);
+        assert_eq!(lines[1].level, LogLevel::Error);
+        assert_eq!(lines[1].message, "SELEC 1");
+        assert_eq!(lines[2].level, LogLevel::Error);
+        assert_eq!(lines[2].content, "\tFROM accounts");
+        assert_eq!(lines[3].pid, Some(7));
+        assert_eq!(
+            lines[3].timestamp.as_deref(),
+            Some("2026-01-15T10:20:32.000Z")
+        );
     }
 }
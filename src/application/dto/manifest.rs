@@ -0,0 +1,270 @@
+use crate::domain::errors::manifest_error::ManifestError;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_MANIFEST_FILE: &str = "guepard.yaml";
+/// Environment variable suggested for `password_env` in saved files.
+pub const DEFAULT_PASSWORD_ENV: &str = "GUEPARD_DB_PASSWORD";
+
+/// Desired state described by `guepard.yaml`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(default = "default_manifest_version")]
+    pub version: u32,
+    #[serde(default)]
+    pub deployments: Vec<ManifestDeployment>,
+}
+
+/// A deployment is identified by its repository name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestDeployment {
+    pub name: String,
+    pub provider: String,
+    #[serde(deserialize_with = "string_or_number")]
+    pub version: String,
+    pub region: String,
+    pub datacenter: String,
+    #[serde(rename = "type", default = "default_deployment_type")]
+    pub deployment_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance_profile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Name of the environment variable holding the database password.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<ManifestBranch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tenets: Vec<ManifestTenet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestBranch {
+    pub name: String,
+    /// Snapshot to branch from; defaults to the snapshot of the attached branch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<String>,
+    #[serde(default)]
+    pub ephemeral: bool,
+}
+
+/// A Tenet proxy is identified by its job ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestTenet {
+    pub job_id: String,
+    pub tenant_id: String,
+    /// Defaults to the deployment's compute FQDN.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_host: Option<String>,
+    /// Defaults to the deployment's compute port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_port: Option<u16>,
+    /// Name of the environment variable holding the masking salt.
+    pub masking_salt_env: String,
+    /// Path to proxy.yaml, relative to the manifest.
+    pub proxy_config: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_port: Option<u16>,
+}
+
+fn default_manifest_version() -> u32 {
+    1
+}
+
+fn default_deployment_type() -> String {
+    "REPOSITORY".to_string()
+}
+
+/// Accept `version: 16` as well as `version: "16"`.
+fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(s) => Ok(s),
+        serde_yaml::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!(
+            "expected a string or number, got {:?}",
+            other
+        ))),
+    }
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self, ManifestError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ManifestError::IoError(format!("Cannot read manifest {}: {}", path.display(), e))
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ManifestError> {
+        let manifest: Manifest =
+            serde_yaml::from_str(content).map_err(|e| ManifestError::ParseError(e.to_string()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<(), ManifestError> {
+        if self.version != 1 {
+            return Err(ManifestError::Invalid(format!(
+                "unsupported manifest version {}",
+                self.version
+            )));
+        }
+
+        let mut names = HashSet::new();
+        for deployment in &self.deployments {
+            if deployment.name.trim().is_empty() {
+                return Err(ManifestError::Invalid(
+                    "deployment name must not be empty".to_string(),
+                ));
+            }
+            if !names.insert(deployment.name.as_str()) {
+                return Err(ManifestError::Invalid(format!(
+                    "deployment '{}' is declared more than once",
+                    deployment.name
+                )));
+            }
+
+            let mut branch_names = HashSet::new();
+            for branch in &deployment.branches {
+                if !branch_names.insert(branch.name.as_str()) {
+                    return Err(ManifestError::Invalid(format!(
+                        "branch '{}' is declared more than once in deployment '{}'",
+                        branch.name, deployment.name
+                    )));
+                }
+            }
+
+            let mut job_ids = HashSet::new();
+            for tenet in &deployment.tenets {
+                if !job_ids.insert(tenet.job_id.as_str()) {
+                    return Err(ManifestError::Invalid(format!(
+                        "tenet job '{}' is declared more than once in deployment '{}'",
+                        tenet.job_id, deployment.name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ManifestDeployment {
+    /// Load a single-deployment request file (`guepard deploy --from-file`).
+    /// Accepts YAML or JSON, either one deployment or a manifest declaring
+    /// exactly one.
+    pub fn load_request(path: &Path) -> Result<Self, ManifestError> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ManifestError::IoError(format!("Cannot read {}: {}", path.display(), e))
+        })?;
+        Self::parse_request(&content)
+    }
+
+    pub fn parse_request(content: &str) -> Result<Self, ManifestError> {
+        let single_err = match serde_yaml::from_str::<ManifestDeployment>(content) {
+            Ok(deployment) => return Ok(deployment),
+            Err(e) => e,
+        };
+        match Manifest::parse(content) {
+            Ok(mut manifest) if manifest.deployments.len() == 1 => {
+                Ok(manifest.deployments.remove(0))
+            }
+            Ok(manifest) => Err(ManifestError::Invalid(format!(
+                "a request file must describe one deployment, found {}",
+                manifest.deployments.len()
+            ))),
+            Err(_) => Err(ManifestError::ParseError(single_err.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+version: 1
+deployments:
+  - name: staging-api
+    provider: PostgreSQL
+    version: 16
+    region: us-west-aws
+    datacenter: us-west-aws
+    performance_profile: gp.g1.xsmall
+    password_env: STAGING_DB_PASSWORD
+    branches:
+      - name: feature-x
+        ephemeral: true
+    tenets:
+      - job_id: staging-tenet
+        tenant_id: staging
+        masking_salt_env: TENET_SALT
+        proxy_config: ./proxy.yaml
+"#;
+
+    #[test]
+    fn parses_sample_manifest_with_defaults() {
+        let manifest = Manifest::parse(SAMPLE).unwrap();
+        assert_eq!(manifest.deployments.len(), 1);
+        let d = &manifest.deployments[0];
+        assert_eq!(d.version, "16");
+        assert_eq!(d.deployment_type, "REPOSITORY");
+        assert!(d.branches[0].ephemeral);
+        assert_eq!(d.tenets[0].job_id, "staging-tenet");
+        assert!(d.tenets[0].upstream_host.is_none());
+    }
+
+    #[test]
+    fn rejects_duplicate_deployments() {
+        let yaml = r#"
+deployments:
+  - { name: a, provider: PostgreSQL, version: "16", region: r, datacenter: d }
+  - { name: a, provider: PostgreSQL, version: "16", region: r, datacenter: d }
+"#;
+        assert!(matches!(
+            Manifest::parse(yaml),
+            Err(ManifestError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        let yaml = r#"
+deployments:
+  - { name: a, provider: PostgreSQL, version: "16", region: r, datacenter: d, colour: red }
+"#;
+        assert!(matches!(
+            Manifest::parse(yaml),
+            Err(ManifestError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn parses_request_file_in_json_and_manifest_form() {
+        let json = r#"{"name": "svc-a", "provider": "PostgreSQL", "version": 16,
+            "region": "us-west-aws", "datacenter": "us-west-aws",
+            "password_env": "SVC_A_PASSWORD"}"#;
+        let d = ManifestDeployment::parse_request(json).unwrap();
+        assert_eq!(d.version, "16");
+        assert_eq!(d.password_env.as_deref(), Some("SVC_A_PASSWORD"));
+
+        let d = ManifestDeployment::parse_request(SAMPLE).unwrap();
+        assert_eq!(d.name, "staging-api");
+
+        let err = ManifestDeployment::parse_request("name: x\nprovider: PostgreSQL\n").unwrap_err();
+        assert!(err.to_string().contains("missing field"));
+    }
+}
@@ -5,5 +5,6 @@ pub mod compute;
 pub mod deploy;
 pub mod log;
 pub mod login;
+pub mod manifest;
 pub mod tenet;
 pub mod usage;
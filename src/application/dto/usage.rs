@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct UsageResponse {
     pub quota_deployments: i32,
     pub quota_snapshots: i32,
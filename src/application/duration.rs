@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use anyhow::Result;
+
+/// Parse "90", "90s", "5m", "1h", "7d" or "2w" into a duration; a bare number
+/// is seconds. Amounts too large to represent are rejected rather than wrapped.
+pub(crate) fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim().to_lowercase();
+    let invalid = || anyhow::anyhow!("Invalid duration '{}': use e.g. 30s, 5m or 1h", value);
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let amount: u64 = value[..split].parse().map_err(|_| invalid())?;
+    let factor = match value[split..].trim() {
+        "" | "s" | "sec" | "secs" | "second" | "seconds" => 1,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+        "d" | "day" | "days" => 86_400,
+        "w" | "week" | "weeks" => 604_800,
+        _ => return Err(invalid()),
+    };
+    let seconds = amount
+        .checked_mul(factor)
+        .ok_or_else(|| anyhow::anyhow!("Duration '{}' is too long", value))?;
+    Ok(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("5s").unwrap(), Duration::from_secs(5));
+        assert_eq!(parse_duration("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_duration("1H").unwrap(), Duration::from_secs(3600));
+        assert_eq!(
+            parse_duration("3 days").unwrap(),
+            Duration::from_secs(259_200)
+        );
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("3y").is_err());
+        assert!(parse_duration("99999999999999999999").is_err());
+        assert!(parse_duration("9999999999999999999h").is_err());
+    }
+}
@@ -0,0 +1,444 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+
+use crate::application::dto::log::{LogLine, LogSource};
+use crate::application::duration::parse_duration;
+use crate::application::output::redact_uri;
+
+/// File formats for `guepard log --output-file`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Ndjson,
+    Csv,
+    Text,
+}
+
+const CSV_HEADER: &str = "timestamp,source,level,pid,message,content,context";
+
+impl ExportFormat {
+    /// The `--format` value, or a guess from the file extension (".csv",
+    /// ".txt" or ".log"), falling back to NDJSON.
+    pub fn resolve(format: Option<&str>, path: &Path) -> Self {
+        let name = format.map(str::to_lowercase).or_else(|| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(str::to_lowercase)
+        });
+        match name.as_deref() {
+            Some("csv") => ExportFormat::Csv,
+            Some("text") | Some("txt") | Some("log") => ExportFormat::Text,
+            _ => ExportFormat::Ndjson,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Text => "text",
+        }
+    }
+}
+
+/// When follow mode starts a new file (`--rotate 50MB`, `--rotate 1h`).
+#[derive(Debug, PartialEq)]
+pub enum Rotation {
+    Size(u64),
+    Age(Duration),
+}
+
+impl Rotation {
+    /// A size with a B, KB, MB or GB suffix (powers of 1024), or a duration
+    /// such as 30m or 1h.
+    pub fn parse(value: &str) -> Result<Self> {
+        let upper = value.trim().to_uppercase();
+        let units = [
+            ("GB", 1024 * 1024 * 1024),
+            ("MB", 1024 * 1024),
+            ("KB", 1024),
+            ("B", 1),
+        ];
+        for (suffix, factor) in units {
+            if let Some(amount) = upper.strip_suffix(suffix) {
+                let amount: u64 = amount.trim().parse().map_err(|_| {
+                    anyhow::anyhow!("Invalid --rotate size '{}': use e.g. 50MB", value)
+                })?;
+                if amount == 0 {
+                    return Err(anyhow::anyhow!("--rotate size must be above zero"));
+                }
+                let bytes = amount
+                    .checked_mul(factor)
+                    .ok_or_else(|| anyhow::anyhow!("--rotate size '{}' is too large", value))?;
+                return Ok(Rotation::Size(bytes));
+            }
+        }
+        let age = parse_duration(value).map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid --rotate '{}': use a size (50MB) or a duration (1h)",
+                value
+            )
+        })?;
+        if age.is_zero() {
+            return Err(anyhow::anyhow!("--rotate duration must be above zero"));
+        }
+        Ok(Rotation::Age(age))
+    }
+}
+
+/// One exported line. Level and source are spelled as in `--json` output;
+/// credentials in the text are masked unless `--show-secrets`.
+#[derive(Serialize)]
+struct Record<'a> {
+    timestamp: Option<&'a str>,
+    source: &'a str,
+    level: Option<String>,
+    pid: Option<u32>,
+    message: String,
+    content: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    context: bool,
+}
+
+impl<'a> Record<'a> {
+    fn from_line(line: &'a LogLine) -> Self {
+        Record {
+            timestamp: line.timestamp.as_deref(),
+            source: match line.source {
+                LogSource::Stdout => "Stdout",
+                LogSource::Stderr => "Stderr",
+            },
+            level: Some(format!("{:?}", line.level)),
+            pid: line.pid,
+            message: redact_uri(&line.message),
+            content: redact_uri(&line.content),
+            context: line.context,
+        }
+    }
+
+    /// A line of the plain-text fallback, which has no stream or level.
+    fn from_raw(content: &'a str, context: bool) -> Self {
+        Record {
+            timestamp: None,
+            source: "Unknown",
+            level: None,
+            pid: None,
+            message: redact_uri(content),
+            content: redact_uri(content),
+            context,
+        }
+    }
+
+    fn render(&self, format: ExportFormat) -> Result<String> {
+        Ok(match format {
+            ExportFormat::Ndjson => serde_json::to_string(self)?,
+            ExportFormat::Csv => [
+                self.timestamp.unwrap_or_default(),
+                self.source,
+                self.level.as_deref().unwrap_or_default(),
+                &self.pid.map(|p| p.to_string()).unwrap_or_default(),
+                &self.message,
+                &self.content,
+                if self.context { "true" } else { "false" },
+            ]
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(","),
+            ExportFormat::Text => format!(
+                "{} {} {} {}",
+                self.timestamp.unwrap_or("-"),
+                self.source,
+                self.level.as_deref().unwrap_or("-"),
+                self.content
+            ),
+        })
+    }
+}
+
+/// Quote a CSV field when it holds a comma, quote or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes log lines to `--output-file`. With a rotation, the current file is
+/// renamed to `<file>.1` (shifting older ones up to `<file>.<keep>`) once it
+/// reaches the size or age limit, and optionally gzipped to `<file>.1.gz`.
+pub struct LogWriter {
+    path: PathBuf,
+    format: ExportFormat,
+    rotation: Option<Rotation>,
+    keep: usize,
+    gzip: bool,
+    file: BufWriter<File>,
+    written: u64,
+    opened: Instant,
+    lines: usize,
+}
+
+impl LogWriter {
+    /// Create the file, replacing it. With a rotation an existing file is
+    /// rotated out first, so restarting a capture keeps what it wrote.
+    pub fn create(
+        path: &Path,
+        format: ExportFormat,
+        rotation: Option<Rotation>,
+        keep: usize,
+        gzip: bool,
+    ) -> Result<Self> {
+        let existing = fs::metadata(path).map(|m| m.len() > 0).unwrap_or(false);
+        if rotation.is_some() && existing {
+            rotate_files(path, keep, gzip)?;
+        }
+        let mut writer = LogWriter {
+            path: path.to_path_buf(),
+            format,
+            rotation,
+            keep,
+            gzip,
+            file: open(path)?,
+            written: 0,
+            opened: Instant::now(),
+            lines: 0,
+        };
+        writer.write_header()?;
+        Ok(writer)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// Lines written across all files.
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+
+    pub fn write_line(&mut self, line: &LogLine) -> Result<()> {
+        let record = Record::from_line(line).render(self.format)?;
+        self.write_record(&record)
+    }
+
+    pub fn write_raw(&mut self, content: &str, context: bool) -> Result<()> {
+        let record = Record::from_raw(content, context).render(self.format)?;
+        self.write_record(&record)
+    }
+
+    /// Flush buffered lines so the file can be read while following.
+    pub fn flush(&mut self) -> Result<()> {
+        self.file
+            .flush()
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if self.format == ExportFormat::Csv {
+            writeln!(self.file, "{}", CSV_HEADER)?;
+            self.written = CSV_HEADER.len() as u64 + 1;
+        }
+        Ok(())
+    }
+
+    fn write_record(&mut self, record: &str) -> Result<()> {
+        let size = record.len() as u64 + 1;
+        let full = match &self.rotation {
+            Some(Rotation::Size(max)) => self.lines > 0 && self.written + size > *max,
+            Some(Rotation::Age(age)) => self.opened.elapsed() >= *age,
+            None => false,
+        };
+        if full {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", record)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+        self.written += size;
+        self.lines += 1;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        self.flush()?;
+        rotate_files(&self.path, self.keep, self.gzip)?;
+        self.file = open(&self.path)?;
+        self.written = 0;
+        self.opened = Instant::now();
+        self.write_header()
+    }
+}
+
+fn open(path: &Path) -> Result<BufWriter<File>> {
+    let file =
+        File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+    Ok(BufWriter::new(file))
+}
+
+fn rotated_path(path: &Path, index: usize, gzip: bool) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", index));
+    if gzip {
+        name.push(".gz");
+    }
+    PathBuf::from(name)
+}
+
+/// Shift `<file>.1` .. `<file>.<keep>` up by one, dropping the oldest, and
+/// move `<file>` to `<file>.1`. With `keep` 0 the current file is discarded.
+fn rotate_files(path: &Path, keep: usize, gzip: bool) -> Result<()> {
+    if keep == 0 {
+        return fs::remove_file(path)
+            .with_context(|| format!("Failed to remove {}", path.display()));
+    }
+    let oldest = rotated_path(path, keep, gzip);
+    if oldest.exists() {
+        fs::remove_file(&oldest)
+            .with_context(|| format!("Failed to remove {}", oldest.display()))?;
+    }
+    for index in (1..keep).rev() {
+        let from = rotated_path(path, index, gzip);
+        if from.exists() {
+            let to = rotated_path(path, index + 1, gzip);
+            fs::rename(&from, &to)
+                .with_context(|| format!("Failed to rename {}", from.display()))?;
+        }
+    }
+    let first = rotated_path(path, 1, false);
+    fs::rename(path, &first).with_context(|| format!("Failed to rename {}", path.display()))?;
+    if gzip {
+        compress(&first, &rotated_path(path, 1, true))?;
+    }
+    Ok(())
+}
+
+fn compress(from: &Path, to: &Path) -> Result<()> {
+    let mut input =
+        File::open(from).with_context(|| format!("Failed to open {}", from.display()))?;
+    let output = File::create(to).with_context(|| format!("Failed to create {}", to.display()))?;
+    let mut encoder = GzEncoder::new(BufWriter::new(output), Compression::default());
+    io::copy(&mut input, &mut encoder)
+        .and_then(|_| encoder.finish()?.flush())
+        .with_context(|| format!("Failed to compress {}", from.display()))?;
+    fs::remove_file(from).with_context(|| format!("Failed to remove {}", from.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::log::LogLevel;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    fn sample(content: &str) -> LogLine {
+        LogLine {
+            timestamp: Some("2026-01-15T10:20:30.123Z".to_string()),
+            level: LogLevel::Error,
+            pid: Some(42),
+            message: "deadlock detected".to_string(),
+            content: content.to_string(),
+            source: LogSource::Stderr,
+            context: false,
+        }
+    }
+
+    #[test]
+    fn test_rotation_parse() {
+        assert_eq!(
+            Rotation::parse("50MB").unwrap(),
+            Rotation::Size(50 * 1024 * 1024)
+        );
+        assert_eq!(
+            Rotation::parse("512kb").unwrap(),
+            Rotation::Size(512 * 1024)
+        );
+        assert_eq!(
+            Rotation::parse("1h").unwrap(),
+            Rotation::Age(Duration::from_secs(3600))
+        );
+        assert!(Rotation::parse("0MB").is_err());
+        assert!(Rotation::parse("99999999999GB").is_err());
+        assert!(Rotation::parse("big").is_err());
+    }
+
+    #[test]
+    fn test_records_per_format() {
+        let line = sample("ERROR:  deadlock detected, \"retry\"");
+        let record = Record::from_line(&line);
+        assert_eq!(
+            record.render(ExportFormat::Csv).unwrap(),
+            "2026-01-15T10:20:30.123Z,Stderr,Error,42,deadlock detected,\"ERROR:  deadlock detected, \"\"retry\"\"\",false"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&record.render(ExportFormat::Ndjson).unwrap()).unwrap();
+        assert_eq!(json["level"], "Error");
+        assert_eq!(json["source"], "Stderr");
+        assert!(json.get("context").is_none());
+        assert_eq!(
+            ExportFormat::resolve(None, Path::new("incident.csv")),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            ExportFormat::resolve(Some("text"), Path::new("incident.csv")),
+            ExportFormat::Text
+        );
+    }
+
+    #[test]
+    fn test_records_mask_credentials() {
+        let line = sample("could not connect to postgresql://app:hunter2@db:5432/app");
+        let text = Record::from_line(&line).render(ExportFormat::Text).unwrap();
+        assert!(!text.contains("hunter2"));
+        assert!(text.ends_with("postgresql://app:********@db:5432/app"));
+    }
+
+    #[test]
+    fn test_size_rotation_keeps_and_compresses() {
+        let dir = std::env::temp_dir().join(format!("guepard-log-export-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("capture.ndjson");
+        let line = sample("x");
+        let size = Record::from_line(&line)
+            .render(ExportFormat::Ndjson)
+            .unwrap()
+            .len() as u64
+            + 1;
+
+        // Two lines per file: of seven lines, one is left in the current file
+        let mut writer = LogWriter::create(
+            &path,
+            ExportFormat::Ndjson,
+            Some(Rotation::Size(size * 2)),
+            2,
+            true,
+        )
+        .unwrap();
+        for _ in 0..7 {
+            writer.write_line(&line).unwrap();
+        }
+        writer.flush().unwrap();
+        assert_eq!(writer.lines(), 7);
+
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+        let mut rotated = String::new();
+        GzDecoder::new(File::open(rotated_path(&path, 1, true)).unwrap())
+            .read_to_string(&mut rotated)
+            .unwrap();
+        assert_eq!(rotated.lines().count(), 2);
+        assert!(rotated_path(&path, 2, true).exists());
+        assert!(!rotated_path(&path, 3, true).exists());
+        assert!(!rotated_path(&path, 1, false).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,8 @@
 pub mod auth;
 pub mod commands;
 pub mod dto;
+pub mod duration;
+pub mod log_export;
 pub mod output;
+pub mod process;
 pub mod services;
@@ -0,0 +1,35 @@
+use std::io;
+use std::process::ExitStatus;
+
+use tokio::process::Child;
+
+/// Wait for a child that shares the terminal. Ctrl+C reaches the child through
+/// the foreground process group, so guepard keeps waiting instead of exiting
+/// and leaving the child on the TTY. Returns the child's status and whether
+/// Ctrl+C was pressed meanwhile.
+pub(crate) async fn wait_in_foreground(child: &mut Child) -> io::Result<(ExitStatus, bool)> {
+    let mut interrupted = false;
+    loop {
+        tokio::select! {
+            status = child.wait() => return Ok((status?, interrupted)),
+            signal = tokio::signal::ctrl_c() => {
+                signal?;
+                interrupted = true;
+            }
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tokio::process::Command;
+
+    #[tokio::test]
+    async fn test_wait_in_foreground_returns_child_status() {
+        let mut child = Command::new("sh").args(["-c", "exit 3"]).spawn().unwrap();
+        let (status, interrupted) = wait_in_foreground(&mut child).await.unwrap();
+        assert_eq!(status.code(), Some(3));
+        assert!(!interrupted);
+    }
+}
@@ -178,6 +178,7 @@ pub async fn checkout_snapshot(
     deployment_id: &str,
     branch_id: &str,
     snapshot_id: &str,
+    discard_changes: bool,
     config: &Config,
 ) -> Result<BranchResponse, BranchError> {
     let jwt_token =
@@ -193,7 +194,7 @@ pub async fn checkout_snapshot(
         .header("Authorization", format!("Bearer {}", jwt_token))
         .json(&serde_json::json!({
             "checkout": true,
-            "discard_changes": "false"
+            "discard_changes": discard_changes.to_string()
         }))
         .send()
         .await
@@ -1,5 +1,8 @@
 use crate::application::auth;
-use crate::application::dto::clone::{CreateCloneRequest, CreateCloneResponse, ListClonesResponse};
+use crate::application::dto::clone::{
+    CreateCloneRequest, CreateCloneResponse, ListAllShadowsResponse, ListClonesResponse,
+    ShadowSummary,
+};
 use crate::config::config::Config;
 use crate::domain::errors::deploy_error::DeployError;
 use reqwest::Client;
@@ -61,3 +64,28 @@ pub async fn list_clones(
         .await
         .map_err(|e| DeployError::ApiError(format!("Failed to parse response: {}", e)))
 }
+
+/// List every shadow in the account, across all deployments.
+pub async fn list_all_clones(config: &Config) -> Result<Vec<ShadowSummary>, DeployError> {
+    let jwt_token =
+        auth::get_auth_token().map_err(|e| DeployError::SessionError(format!("{}", e)))?;
+    let client = Client::new();
+    let url = format!("{}/deploy/shadow", config.api_url);
+
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", jwt_token))
+        .send()
+        .await
+        .map_err(|e| DeployError::ApiError(format!("Network error: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(DeployError::from_response(response).await);
+    }
+
+    response
+        .json::<ListAllShadowsResponse>()
+        .await
+        .map(ListAllShadowsResponse::into_shadows)
+        .map_err(|e| DeployError::ApiError(format!("Failed to parse response: {}", e)))
+}
@@ -191,6 +191,20 @@ pub async fn create_commit(
     create_commit_with_deps(deployment_id, branch_id, request, config, &auth_provider).await
 }
 
+/// Comment used for the snapshot taken before changes are discarded.
+pub const SAFETY_SNAPSHOT_COMMENT: &str = "pre-checkout safety snapshot";
+
+pub async fn create_safety_snapshot(
+    deployment_id: &str,
+    branch_id: &str,
+    config: &Config,
+) -> Result<CreateCommitResponse, BookmarkError> {
+    let request = CreateCommitRequest {
+        snapshot_comment: SAFETY_SNAPSHOT_COMMENT.to_string(),
+    };
+    create_commit(deployment_id, branch_id, request, config).await
+}
+
 pub async fn checkout_bookmark_with_deps<A: AuthProvider>(
     deployment_id: &str,
     branch_id: &str,
@@ -1,12 +1,16 @@
 use crate::application::auth;
 use crate::application::dto::compute::{
-    ComputeStatusResponse, ListComputeResponse, LogsResponse, StatusErrorResponse,
+    ComputeState, ComputeStatusResponse, ListComputeResponse, LogsResponse, StatusErrorResponse,
 };
 use crate::config::config::Config;
 use crate::domain::errors::compute_error::ComputeError;
 
 use anyhow::Result;
 use reqwest::{Client, StatusCode};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 // Trait for dependency injection to make testing easier
 #[cfg_attr(test, mockall::automock)]
@@ -207,6 +211,10 @@ pub async fn get_status_with_deps<A: AuthProvider>(
                 .map_err(|e| ComputeError::ParseError(e.to_string()))?;
             Err(ComputeError::NotHealthy(error.message))
         }
+        StatusCode::NOT_FOUND => {
+            let text = response.text().await.unwrap_or("No details".to_string());
+            Err(ComputeError::NotFound(text))
+        }
         status => {
             let text = response.text().await.unwrap_or("No details".to_string());
             Err(ComputeError::Unexpected(format!(
@@ -225,6 +233,101 @@ pub async fn get_status(
     get_status_with_deps(deployment_id, config, &auth_provider).await
 }
 
+/// Lifecycle state of the compute. A "not healthy" answer is folded into a
+/// state inferred from its message instead of being returned as an error.
+pub async fn get_state(
+    deployment_id: &str,
+    config: &Config,
+) -> Result<(ComputeState, ComputeStatusResponse), ComputeError> {
+    match get_status(deployment_id, config).await {
+        Ok(status) => Ok((status.state(), status)),
+        Err(ComputeError::NotHealthy(message)) => Ok((
+            ComputeState::from_message(&message),
+            ComputeStatusResponse {
+                message: Some(message),
+                ..Default::default()
+            },
+        )),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether an observed state satisfies the state being waited for. A compute
+/// that fails its health check after a stop is down, so it counts as stopped.
+fn state_reached(state: ComputeState, target: ComputeState) -> bool {
+    state == target || (target == ComputeState::Stopped && state == ComputeState::Failed)
+}
+
+/// Poll the status endpoint until the compute reports `target` or `timeout` elapses.
+pub async fn wait_for_state(
+    deployment_id: &str,
+    config: &Config,
+    target: ComputeState,
+    timeout: Duration,
+) -> Result<ComputeStatusResponse, ComputeError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let last_error = match get_state(deployment_id, config).await {
+            Ok((state, status)) if state_reached(state, target) => return Ok(status),
+            Ok((state, status)) => match status.message {
+                Some(message) => format!("{} ({})", state, message),
+                None => state.to_string(),
+            },
+            Err(ComputeError::SessionError(e)) => return Err(ComputeError::SessionError(e)),
+            // A compute that is gone is as stopped as it gets
+            Err(ComputeError::NotFound(_)) if target == ComputeState::Stopped => {
+                return Ok(ComputeStatusResponse::default())
+            }
+            Err(e) => e.to_string(),
+        };
+        if Instant::now() + HEALTH_POLL_INTERVAL > deadline {
+            return Err(ComputeError::Timeout(format!(
+                "compute for deployment {} not {} after {}s (last status: {})",
+                deployment_id,
+                target,
+                timeout.as_secs(),
+                last_error
+            )));
+        }
+        sleep(HEALTH_POLL_INTERVAL).await;
+    }
+}
+
+/// After a change that restarts the compute, poll until the status stops
+/// reporting running, so that a following wait for running is not satisfied by
+/// the status from before the restart. Returns false if the compute still
+/// reported running when `timeout` elapsed (the restart may have been quicker
+/// than a poll).
+pub async fn wait_until_restarting(
+    deployment_id: &str,
+    config: &Config,
+    timeout: Duration,
+) -> Result<bool, ComputeError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match get_state(deployment_id, config).await {
+            Ok((ComputeState::Running, _)) => {}
+            Ok(_) => return Ok(true),
+            Err(ComputeError::SessionError(e)) => return Err(ComputeError::SessionError(e)),
+            // Unreachable while it restarts
+            Err(_) => return Ok(true),
+        }
+        if Instant::now() + HEALTH_POLL_INTERVAL > deadline {
+            return Ok(false);
+        }
+        sleep(HEALTH_POLL_INTERVAL).await;
+    }
+}
+
+/// Poll the status endpoint until the compute reports running or `timeout` elapses.
+pub async fn wait_until_healthy(
+    deployment_id: &str,
+    config: &Config,
+    timeout: Duration,
+) -> Result<ComputeStatusResponse, ComputeError> {
+    wait_for_state(deployment_id, config, ComputeState::Running, timeout).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,4 +378,15 @@ mod tests {
         let r4 = get_status_with_deps("dep-1", &config, &auth).await;
         assert!(r4.is_err());
     }
+
+    #[test]
+    fn test_state_reached() {
+        assert!(state_reached(ComputeState::Running, ComputeState::Running));
+        assert!(state_reached(ComputeState::Failed, ComputeState::Stopped));
+        assert!(!state_reached(ComputeState::Failed, ComputeState::Running));
+        assert!(!state_reached(
+            ComputeState::Stopping,
+            ComputeState::Stopped
+        ));
+    }
 }
@@ -167,6 +167,38 @@ pub async fn list_deployments(
     list_deployments_with_deps(config, &auth_provider).await
 }
 
+pub async fn list_f2_deployments_with_deps<A: AuthProvider>(
+    config: &Config,
+    auth_provider: &A,
+) -> Result<Vec<ListDeploymentsResponse>, DeployError> {
+    let jwt_token = auth_provider
+        .get_auth_token()
+        .map_err(|e| DeployError::SessionError(format!("{}", e)))?;
+    let client = Client::new();
+    let response = client
+        .get(format!("{}/deploy/f2", config.api_url))
+        .header("Authorization", format!("Bearer {}", jwt_token))
+        .send()
+        .await
+        .map_err(DeployError::RequestFailed)?;
+
+    if response.status().is_success() {
+        response
+            .json::<Vec<ListDeploymentsResponse>>()
+            .await
+            .map_err(|e| DeployError::ParseError(e.to_string()))
+    } else {
+        Err(DeployError::from_response(response).await)
+    }
+}
+
+pub async fn list_f2_deployments(
+    config: &Config,
+) -> Result<Vec<ListDeploymentsResponse>, DeployError> {
+    let auth_provider = DefaultAuthProvider;
+    list_f2_deployments_with_deps(config, &auth_provider).await
+}
+
 pub async fn get_deployment_with_deps<A: AuthProvider>(
     deployment_id: &str,
     config: &Config,
@@ -0,0 +1,327 @@
+use crate::application::auth;
+use crate::config::config::Config;
+use crate::domain::errors::deploy_error::DeployError;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImageProvider {
+    pub id: String,
+    #[serde(default)]
+    pub account_id: Option<String>,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_default: bool,
+    pub catalog: ImageCatalog,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImageCatalog {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub image_name: String,
+    pub database_provider: String,
+    pub database_version: String,
+    #[serde(default)]
+    pub default_port: Option<i32>,
+    #[serde(default)]
+    pub image_type: Option<String>,
+    #[serde(default)]
+    pub image_source: Option<String>,
+    #[serde(default)]
+    pub support_status: Option<String>,
+    #[serde(default)]
+    pub min_cpu: Option<i32>,
+    #[serde(default)]
+    pub min_memory: Option<i32>,
+}
+
+// Trait for dependency injection to make testing easier
+#[cfg_attr(test, mockall::automock)]
+pub trait AuthProvider {
+    fn get_auth_token(&self) -> Result<String, crate::domain::errors::config_error::ConfigError>;
+}
+
+// Default implementation that uses the real auth module
+pub struct DefaultAuthProvider;
+
+impl AuthProvider for DefaultAuthProvider {
+    fn get_auth_token(&self) -> Result<String, crate::domain::errors::config_error::ConfigError> {
+        auth::get_auth_token()
+    }
+}
+
+pub async fn list_image_providers_with_deps<A: AuthProvider>(
+    config: &Config,
+    auth_provider: &A,
+) -> Result<Vec<ImageProvider>, DeployError> {
+    let token = auth_provider
+        .get_auth_token()
+        .map_err(|e| DeployError::SessionError(format!("{}", e)))?;
+
+    let client = Client::new();
+    let response = client
+        .get(format!("{}/image-providers", config.api_url))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .map_err(DeployError::RequestFailed)?;
+
+    if !response.status().is_success() {
+        return Err(DeployError::from_response(response).await);
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| DeployError::ParseError(e.to_string()))
+}
+
+pub async fn list_image_providers(config: &Config) -> Result<Vec<ImageProvider>, DeployError> {
+    let auth_provider = DefaultAuthProvider;
+    list_image_providers_with_deps(config, &auth_provider).await
+}
+
+/// Find an image provider by ID or image name (e.g. `guepardrun/postgres:16`).
+pub(crate) fn find_image_provider<'a>(
+    providers: &'a [ImageProvider],
+    selector: &str,
+) -> Result<&'a ImageProvider, DeployError> {
+    if let Some(provider) = providers
+        .iter()
+        .find(|p| p.id == selector || p.catalog.image_name == selector)
+    {
+        return Ok(provider);
+    }
+
+    let names: Vec<&str> = providers
+        .iter()
+        .filter(|p| p.is_active)
+        .map(|p| p.catalog.image_name.as_str())
+        .collect();
+    Err(DeployError::ApiError(format!(
+        "Unknown image provider '{}'.{}",
+        selector,
+        did_you_mean(selector, &names)
+    )))
+}
+
+/// Check a provider/version pair against the active image providers before
+/// sending a deployment request. Returns the matching image with the
+/// provider's canonical spelling.
+pub(crate) fn resolve_image<'a>(
+    providers: &'a [ImageProvider],
+    database_provider: &str,
+    database_version: &str,
+) -> Result<&'a ImageProvider, DeployError> {
+    let active: Vec<&ImageProvider> = providers.iter().filter(|p| p.is_active).collect();
+
+    let matching_provider: Vec<&ImageProvider> = active
+        .iter()
+        .copied()
+        .filter(|p| {
+            p.catalog
+                .database_provider
+                .eq_ignore_ascii_case(database_provider)
+        })
+        .collect();
+
+    if matching_provider.is_empty() {
+        let names = unique(active.iter().map(|p| p.catalog.database_provider.as_str()));
+        return Err(DeployError::ApiError(format!(
+            "Unknown database provider '{}'.{} Available: {}",
+            database_provider,
+            did_you_mean(database_provider, &names),
+            names.join(", ")
+        )));
+    }
+
+    if let Some(image) = matching_provider
+        .iter()
+        .find(|p| p.catalog.database_version == database_version)
+    {
+        return Ok(image);
+    }
+
+    let canonical = &matching_provider[0].catalog.database_provider;
+    let versions = unique(
+        matching_provider
+            .iter()
+            .map(|p| p.catalog.database_version.as_str()),
+    );
+    Err(DeployError::ApiError(format!(
+        "Version '{}' is not available for {}.{} Available: {}",
+        database_version,
+        canonical,
+        did_you_mean(database_version, &versions),
+        versions.join(", ")
+    )))
+}
+
+/// Default version for a provider: the default image if flagged, else the first one listed.
+pub(crate) fn default_version<'a>(
+    providers: &'a [ImageProvider],
+    database_provider: &str,
+) -> Option<&'a str> {
+    let mut candidates = providers.iter().filter(|p| {
+        p.is_active
+            && p.catalog
+                .database_provider
+                .eq_ignore_ascii_case(database_provider)
+    });
+    let first = candidates.clone().next();
+    candidates
+        .find(|p| p.is_default)
+        .or(first)
+        .map(|p| p.catalog.database_version.as_str())
+}
+
+pub(crate) fn provider_names(providers: &[ImageProvider]) -> Vec<&str> {
+    unique(
+        providers
+            .iter()
+            .filter(|p| p.is_active)
+            .map(|p| p.catalog.database_provider.as_str()),
+    )
+}
+
+fn unique<'a>(values: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut seen: Vec<&str> = Vec::new();
+    for value in values {
+        if !seen.contains(&value) {
+            seen.push(value);
+        }
+    }
+    seen
+}
+
+/// " Did you mean 'X'?" for the closest candidate, or an empty string.
+fn did_you_mean(input: &str, candidates: &[&str]) -> String {
+    match closest_match(input, candidates) {
+        Some(candidate) => format!(" Did you mean '{}'?", candidate),
+        None => String::new(),
+    }
+}
+
+pub(crate) fn closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    // Every candidate starts with "", so an empty input suggests nothing
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+    candidates
+        .iter()
+        .map(|c| {
+            let lower = c.to_lowercase();
+            let distance = if lower.starts_with(&input) || input.starts_with(&lower) {
+                0
+            } else {
+                edit_distance(&input, &lower)
+            };
+            (distance, *c)
+        })
+        .filter(|(distance, c)| *distance <= (c.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, c)| c)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(provider: &str, version: &str, is_default: bool) -> ImageProvider {
+        ImageProvider {
+            id: format!("{}-{}", provider, version),
+            account_id: None,
+            is_active: true,
+            is_default,
+            catalog: ImageCatalog {
+                id: None,
+                image_name: format!("guepardrun/{}:{}", provider.to_lowercase(), version),
+                database_provider: provider.to_string(),
+                database_version: version.to_string(),
+                default_port: None,
+                image_type: None,
+                image_source: None,
+                support_status: None,
+                min_cpu: None,
+                min_memory: None,
+            },
+        }
+    }
+
+    fn catalog() -> Vec<ImageProvider> {
+        vec![
+            image("PostgreSQL", "16", true),
+            image("PostgreSQL", "17", false),
+            image("MySQL", "8.0", true),
+        ]
+    }
+
+    #[test]
+    fn test_resolve_image_is_case_insensitive() {
+        let providers = catalog();
+        let image = resolve_image(&providers, "postgresql", "17").unwrap();
+        assert_eq!(image.catalog.database_provider, "PostgreSQL");
+        assert_eq!(image.catalog.database_version, "17");
+    }
+
+    #[test]
+    fn test_resolve_image_suggests_provider() {
+        let providers = catalog();
+        let err = resolve_image(&providers, "postgres", "16").unwrap_err();
+        assert!(err.to_string().contains("Did you mean 'PostgreSQL'?"));
+
+        let err = resolve_image(&providers, "mysq", "8.0").unwrap_err();
+        assert!(err.to_string().contains("Did you mean 'MySQL'?"));
+    }
+
+    #[test]
+    fn test_resolve_image_lists_versions() {
+        let providers = catalog();
+        let err = resolve_image(&providers, "PostgreSQL", "15").unwrap_err();
+        assert!(err.to_string().contains("Available: 16, 17"));
+    }
+
+    #[test]
+    fn test_closest_match_rejects_unrelated_input() {
+        assert_eq!(closest_match("oracle", &["PostgreSQL", "MySQL"]), None);
+        assert_eq!(closest_match("", &["PostgreSQL", "MySQL"]), None);
+    }
+
+    #[test]
+    fn test_default_version_prefers_default_image() {
+        let providers = catalog();
+        assert_eq!(default_version(&providers, "PostgreSQL"), Some("16"));
+        assert_eq!(default_version(&providers, "MongoDB"), None);
+    }
+
+    #[test]
+    fn test_parse_image_providers_response() {
+        let body = r#"[{"id":"c61d","account_id":null,"is_active":true,"is_default":true,
+            "catalog":{"id":"41fe","image_name":"guepardrun/postgres:16","database_version":"16",
+            "database_provider":"PostgreSQL","default_port":5432,"support_status":"SUPPORTED"}}]"#;
+        let providers: Vec<ImageProvider> = serde_json::from_str(body).unwrap();
+        assert_eq!(providers[0].catalog.default_port, Some(5432));
+        assert!(find_image_provider(&providers, "guepardrun/postgres:16").is_ok());
+    }
+}
@@ -3,6 +3,7 @@ pub mod clone;
 pub mod commit;
 pub mod compute;
 pub mod deploy;
+pub mod image;
 pub mod login;
 pub mod performance;
 pub mod tenet;
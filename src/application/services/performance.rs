@@ -2,7 +2,7 @@ use crate::application::auth;
 use crate::config::config::Config;
 use crate::domain::errors::deploy_error::DeployError;
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct PerformanceProfile {
@@ -17,6 +17,15 @@ pub struct PerformanceProfile {
     pub is_active: bool,
 }
 
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ApplyPerformanceProfileResponse {
+    pub message: Option<String>,
+    pub deployment_id: Option<String>,
+    pub performance_profile_id: Option<String>,
+    pub applied_date: Option<String>,
+    pub restart_required: Option<bool>,
+}
+
 // Trait for dependency injection to make testing easier
 #[cfg_attr(test, mockall::automock)]
 pub trait AuthProvider {
@@ -91,9 +100,98 @@ pub async fn get_performance_profile_by_label(
     )))
 }
 
+pub async fn apply_performance_profile_with_deps<A: AuthProvider>(
+    deployment_id: &str,
+    performance_profile_id: &str,
+    config: &Config,
+    auth_provider: &A,
+) -> Result<ApplyPerformanceProfileResponse, DeployError> {
+    let token = auth_provider
+        .get_auth_token()
+        .map_err(|e| DeployError::SessionError(format!("{}", e)))?;
+
+    let client = Client::new();
+    let response = client
+        .post(format!(
+            "{}/deploy/{}/performance/{}",
+            config.api_url, deployment_id, performance_profile_id
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .send()
+        .await
+        .map_err(DeployError::RequestFailed)?;
+
+    if !response.status().is_success() {
+        return Err(DeployError::from_response(response).await);
+    }
+
+    let text = response.text().await.unwrap_or_default();
+    if text.trim().is_empty() {
+        return Ok(ApplyPerformanceProfileResponse::default());
+    }
+    serde_json::from_str::<ApplyPerformanceProfileResponse>(&text).or_else(|_| {
+        Ok(ApplyPerformanceProfileResponse {
+            message: Some(text),
+            ..Default::default()
+        })
+    })
+}
+
+pub async fn apply_performance_profile(
+    deployment_id: &str,
+    performance_profile_id: &str,
+    config: &Config,
+) -> Result<ApplyPerformanceProfileResponse, DeployError> {
+    let auth_provider = DefaultAuthProvider;
+    apply_performance_profile_with_deps(
+        deployment_id,
+        performance_profile_id,
+        config,
+        &auth_provider,
+    )
+    .await
+}
+
+/// Strict lookup used when changing the profile of an existing deployment: the
+/// label must exist for exactly this provider and version.
+pub(crate) fn find_profile_for_deployment<'a>(
+    profiles: &'a [PerformanceProfile],
+    label_name: &str,
+    database_provider: &str,
+    database_version: &str,
+) -> Result<&'a PerformanceProfile, DeployError> {
+    let candidates: Vec<&PerformanceProfile> = profiles
+        .iter()
+        .filter(|p| {
+            p.is_active
+                && p.database_provider.eq_ignore_ascii_case(database_provider)
+                && p.database_version == database_version
+        })
+        .collect();
+
+    if let Some(profile) = candidates.iter().find(|p| p.label_name == label_name) {
+        return Ok(profile);
+    }
+
+    let mut available: Vec<&str> = candidates.iter().map(|p| p.label_name.as_str()).collect();
+    available.sort_unstable();
+    available.dedup();
+    Err(DeployError::ApiError(format!(
+        "Performance profile '{}' is not available for {} {}. Available: {}",
+        label_name,
+        database_provider,
+        database_version,
+        if available.is_empty() {
+            "none".to_string()
+        } else {
+            available.join(", ")
+        }
+    )))
+}
+
 // Pure selector to make the matching logic unit-testable without HTTP
 pub(crate) fn select_profile_id(
-    profiles: &Vec<PerformanceProfile>,
+    profiles: &[PerformanceProfile],
     label_name: &str,
     database_provider: &str,
     database_version: &str,
@@ -237,4 +335,33 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn test_find_profile_for_deployment_is_strict() {
+        let profile = |id: &str, label: &str, provider: &str, version: &str| PerformanceProfile {
+            id: id.to_string(),
+            label_name: label.to_string(),
+            description_text: String::new(),
+            database_provider: provider.to_string(),
+            database_version: version.to_string(),
+            min_cpu: 1,
+            min_memory: 512,
+            is_default: false,
+            is_active: true,
+        };
+        let profiles = vec![
+            profile("1", "gp.g1.xsmall", "PostgreSQL", "16"),
+            profile("2", "gp.g1.medium", "PostgreSQL", "16"),
+            profile("3", "gp.g1.large", "PostgreSQL", "17"),
+        ];
+
+        let found = find_profile_for_deployment(&profiles, "gp.g1.medium", "postgresql", "16");
+        assert_eq!(found.unwrap().id, "2");
+
+        // Exists, but not for this version: no label-only fallback here
+        let err = find_profile_for_deployment(&profiles, "gp.g1.large", "PostgreSQL", "16")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("gp.g1.medium, gp.g1.xsmall"));
+    }
 }
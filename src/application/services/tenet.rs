@@ -181,6 +181,7 @@ pub async fn get_proxy_yaml_with_deps<A: TenetAuthProvider>(
             .text()
             .await
             .map_err(|e| TenetError::ParseError(e.to_string())),
+        StatusCode::NOT_FOUND => Err(TenetError::NotFound(read_error_body(response).await)),
         status => {
             let text = read_error_body(response).await;
             Err(TenetError::Unexpected(format!(
@@ -1,9 +1,13 @@
 use crate::application::auth;
 use crate::application::dto::usage::UsageResponse;
-use crate::config::config::Config;
+use crate::config::config::{load_config_data, Config};
+use crate::domain::errors::quota_error::QuotaError;
 use crate::domain::errors::usage_error::UsageError;
 use anyhow::Result;
+use colored::Colorize;
 use reqwest::{Client, StatusCode};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Mutex;
 
 #[cfg(test)]
 use mockall::predicate::*;
@@ -69,6 +73,113 @@ pub async fn get_usage(config: &Config) -> Result<UsageResponse, UsageError> {
     get_usage_with_deps(config, &auth_provider).await
 }
 
+/// Warn once creating a resource brings usage to this percentage of the quota.
+pub const DEFAULT_QUOTA_WARN_THRESHOLD: u8 = 80;
+
+static SKIP_QUOTA_CHECK: AtomicBool = AtomicBool::new(false);
+
+// Usage is fetched once per invocation; each passed check counts the new
+// resource so several creations in one run (e.g. `apply`) are checked together.
+static USAGE_CACHE: Mutex<Option<UsageResponse>> = Mutex::const_new(None);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaResource {
+    Deployment,
+    Snapshot,
+    Clone,
+}
+
+impl QuotaResource {
+    fn plural(self) -> &'static str {
+        match self {
+            QuotaResource::Deployment => "deployments",
+            QuotaResource::Snapshot => "snapshots",
+            QuotaResource::Clone => "clones",
+        }
+    }
+
+    fn usage_mut(self, usage: &mut UsageResponse) -> (&mut i32, i32) {
+        match self {
+            QuotaResource::Deployment => (&mut usage.usage_deployments, usage.quota_deployments),
+            QuotaResource::Snapshot => (&mut usage.usage_snapshots, usage.quota_snapshots),
+            QuotaResource::Clone => (&mut usage.usage_clones, usage.quota_clones),
+        }
+    }
+}
+
+/// Disable pre-flight quota checks for this invocation (`--skip-quota-check`).
+pub fn set_skip_quota_check(skip: bool) {
+    SKIP_QUOTA_CHECK.store(skip, Ordering::Relaxed);
+}
+
+/// Check whether one more `resource` fits in the quota. Returns a warning when
+/// usage after creating it reaches `warn_threshold` percent. A negative quota
+/// means unlimited.
+pub(crate) fn check_quota(
+    usage: &UsageResponse,
+    resource: QuotaResource,
+    warn_threshold: u8,
+) -> Result<Option<String>, QuotaError> {
+    let mut usage = usage.clone();
+    let (used, quota) = resource.usage_mut(&mut usage);
+    let used = *used;
+    if quota < 0 {
+        return Ok(None);
+    }
+    if used >= quota {
+        return Err(QuotaError::Exceeded {
+            resource: resource.plural(),
+            used,
+            quota,
+        });
+    }
+    let after = used + 1;
+    if quota > 0 && after * 100 >= quota * i32::from(warn_threshold) {
+        return Ok(Some(format!(
+            "{}/{} {} will be used after this one ({}% of your quota)",
+            after,
+            quota,
+            resource.plural(),
+            after * 100 / quota
+        )));
+    }
+    Ok(None)
+}
+
+/// Fail fast before creating a resource the quota has no room for. If usage
+/// cannot be fetched the check is skipped with a warning and the server decides.
+pub async fn ensure_quota(resource: QuotaResource, config: &Config) -> Result<(), QuotaError> {
+    if SKIP_QUOTA_CHECK.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    let mut cache = USAGE_CACHE.lock().await;
+    if cache.is_none() {
+        match get_usage(config).await {
+            Ok(usage) => *cache = Some(usage),
+            Err(e) => {
+                eprintln!(
+                    "{} Could not check quota ({}); continuing",
+                    "⚠️".yellow(),
+                    e
+                );
+                return Ok(());
+            }
+        }
+    }
+    let usage = cache.as_mut().unwrap();
+
+    let threshold = load_config_data()
+        .ok()
+        .and_then(|c| c.quota_warn_threshold)
+        .unwrap_or(DEFAULT_QUOTA_WARN_THRESHOLD);
+    if let Some(warning) = check_quota(usage, resource, threshold)? {
+        eprintln!("{} {}", "⚠️".yellow(), warning);
+    }
+    *resource.usage_mut(usage).0 += 1;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -176,4 +287,39 @@ mod tests {
             | UsageError::InternalServerError(_) => {}
         }
     }
+
+    fn usage(used_deployments: i32, quota_deployments: i32) -> UsageResponse {
+        UsageResponse {
+            quota_deployments,
+            quota_snapshots: 10,
+            quota_clones: -1,
+            usage_deployments: used_deployments,
+            usage_snapshots: 0,
+            usage_clones: 50,
+        }
+    }
+
+    #[test]
+    fn test_check_quota_exhausted() {
+        let err = check_quota(&usage(2, 2), QuotaResource::Deployment, 80).unwrap_err();
+        assert!(err.to_string().contains("2/2 deployments used"));
+    }
+
+    #[test]
+    fn test_check_quota_warns_at_threshold() {
+        let warning = check_quota(&usage(3, 5), QuotaResource::Deployment, 80).unwrap();
+        assert_eq!(
+            warning.as_deref(),
+            Some("4/5 deployments will be used after this one (80% of your quota)")
+        );
+        assert_eq!(
+            check_quota(&usage(2, 5), QuotaResource::Deployment, 80).unwrap(),
+            None
+        );
+        // Negative quota is unlimited
+        assert_eq!(
+            check_quota(&usage(0, 5), QuotaResource::Clone, 80).unwrap(),
+            None
+        );
+    }
 }
@@ -1,14 +1,16 @@
 use clap::Parser;
 use guepard_cli::application::commands::{
-    branch, checkout, clone, commit, compute, config, deploy, list, log, login, logout, tenet,
-    usage,
+    apply, branch, checkout, clone, commit, compute, config, connect, connection, deploy, doctor,
+    env, exec, list, log, login, logout, migrate, status, tenet, usage, wait,
 };
-use guepard_cli::application::output::OutputFormat;
+use guepard_cli::application::output::{set_show_secrets, OutputFormat};
+use guepard_cli::application::services::usage::set_skip_quota_check;
 use guepard_cli::config::config::{load_config, Config};
 use guepard_cli::domain::errors::{
     bookmark_error::BookmarkError, branch_error::BranchError, compute_error::ComputeError,
-    deploy_error::DeployError, login_error::LoginError, tenet_error::TenetError,
-    usage_error::UsageError,
+    deploy_error::DeployError, exit_error::ExitError, login_error::LoginError,
+    manifest_error::ManifestError, quota_error::QuotaError, tenet_error::TenetError,
+    usage_error::UsageError, wait_error::WaitError,
 };
 use guepard_cli::structure::{SubCommand, CLI};
 
@@ -16,6 +18,8 @@ use guepard_cli::structure::{SubCommand, CLI};
 async fn main() {
     let args = CLI::parse();
     let sub_commands: &SubCommand = &args.sub_commands;
+    set_show_secrets(args.show_secrets);
+    set_skip_quota_check(args.skip_quota_check);
 
     let config = match load_config() {
         Ok(cfg) => cfg,
@@ -51,6 +55,18 @@ async fn main() {
         } else if let Some(usage_error) = err.downcast_ref::<UsageError>() {
             eprintln!("❌ {}", usage_error);
             exit_code = 6;
+        } else if let Some(manifest_error) = err.downcast_ref::<ManifestError>() {
+            eprintln!("❌ {}", manifest_error);
+            exit_code = 9;
+        } else if let Some(quota_error) = err.downcast_ref::<QuotaError>() {
+            eprintln!("❌ {}", quota_error);
+            exit_code = 10;
+        } else if let Some(wait_error) = err.downcast_ref::<WaitError>() {
+            eprintln!("❌ {}", wait_error);
+            exit_code = 11;
+        } else if let Some(exit_error) = err.downcast_ref::<ExitError>() {
+            eprintln!("❌ {}", exit_error);
+            exit_code = exit_error.code();
         } else {
             eprintln!("{}", err);
             exit_code = 1;
@@ -162,5 +178,78 @@ async fn run(sub_commands: &SubCommand, config: &Config) -> anyhow::Result<()> {
             };
             clone::clone_command(args, config, output_format).await
         }
+        SubCommand::Status(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            status::status(args, config, output_format).await
+        }
+        SubCommand::Plan(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            apply::plan(args, config, output_format).await
+        }
+        SubCommand::Apply(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            apply::apply(args, config, output_format).await
+        }
+        SubCommand::Connection(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            connection::connection(args, config, output_format).await
+        }
+        SubCommand::Env(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            env::env(args, config, output_format).await
+        }
+        SubCommand::Connect(args) => connect::connect(args, config).await,
+        SubCommand::Migrate(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            migrate::migrate(args, config, output_format).await
+        }
+        SubCommand::Exec(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            exec::exec(args, config, output_format).await
+        }
+        SubCommand::Doctor(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            doctor::doctor(args, config, output_format).await
+        }
+        SubCommand::Wait(args) => {
+            let output_format = if args.output.json {
+                OutputFormat::Json
+            } else {
+                OutputFormat::Table
+            };
+            wait::wait(args, config, output_format).await
+        }
     }
 }
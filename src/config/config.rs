@@ -9,6 +9,7 @@ use std::env;
 use std::fs::{self, File};
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
 
 const DEFAULT_APP_URL: &str = "https://app.guepard.run";
 const DEFAULT_API_URL: &str = "https://api.guepard.run";
@@ -220,6 +221,44 @@ pub fn delete_jwt_token() -> Result<(), ConfigError> {
     }
 }
 
+/// Store a deployment's database password locally (OS keyring, or a 0600 file
+/// in builds without keyring support). Returns where it was stored.
+pub fn save_database_password(deployment_id: &str, password: &str) -> Result<String, ConfigError> {
+    #[cfg(feature = "keyring")]
+    {
+        let account = format!("db-password:{}", deployment_id);
+        let entry = Entry::new("guepard-cli", &account).map_err(|e| {
+            ConfigError::KeyringError(format!("Failed to access keyring entry: {}", e))
+        })?;
+        entry.set_password(password).map_err(|e| {
+            ConfigError::KeyringError(format!("Failed to store database password: {}", e))
+        })?;
+        Ok(format!(
+            "keyring (service guepard-cli, account {})",
+            account
+        ))
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    {
+        let dir = dirs::home_dir()
+            .ok_or_else(|| ConfigError::IoError("Home directory not found".to_string()))?
+            .join(".guepard/secrets");
+        fs::create_dir_all(&dir).map_err(|e| {
+            ConfigError::IoError(format!("Failed to create secrets directory: {}", e))
+        })?;
+        let path = dir.join(deployment_id);
+        fs::write(&path, password)
+            .map_err(|e| ConfigError::IoError(format!("Failed to write password file: {}", e)))?;
+
+        #[cfg(unix)]
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).map_err(|e| {
+            ConfigError::IoError(format!("Failed to set password file permissions: {}", e))
+        })?;
+        Ok(path.display().to_string())
+    }
+}
+
 pub fn delete_session() -> Result<(), ConfigError> {
     debug!("Starting session deletion");
 
@@ -260,11 +299,37 @@ pub fn is_logged_in() -> bool {
     }
 }
 
+/// Describe where the session token is stored, failing when the OS keyring
+/// this build relies on cannot be reached.
+pub fn token_storage() -> Result<&'static str, ConfigError> {
+    #[cfg(feature = "keyring")]
+    {
+        let entry = Entry::new("guepard-cli", "session").map_err(|e| {
+            ConfigError::KeyringError(format!("Failed to access keyring entry: {}", e))
+        })?;
+        match entry.get_password() {
+            Ok(_) | Err(keyring::Error::NoEntry) => Ok("OS keyring"),
+            Err(e) => Err(ConfigError::KeyringError(format!(
+                "Keyring unavailable: {}",
+                e
+            ))),
+        }
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    {
+        Ok("file ~/.guepard/session.jwt (built without keyring support)")
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ConfigData {
     pub api_url: String,
     #[serde(default = "default_app_url")]
     pub app_url: String,
+    /// Usage percentage at which creating commands warn about a quota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota_warn_threshold: Option<u8>,
 }
 
 fn default_app_url() -> String {
@@ -300,6 +365,7 @@ pub fn load_config_data() -> Result<ConfigData, ConfigError> {
         return Ok(ConfigData {
             api_url: "https://api.guepard.run".to_string(),
             app_url: default_app_url(),
+            quota_warn_threshold: None,
         });
     }
 
@@ -310,3 +376,144 @@ pub fn load_config_data() -> Result<ConfigData, ConfigError> {
 
     Ok(config_data)
 }
+
+pub const PROJECT_FILE_NAME: &str = ".guepard.json";
+
+/// Per-directory project binding, stored in `.guepard.json` at the project root.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ProjectFile {
+    pub deployment_id: Option<String>,
+    pub branch_id: Option<String>,
+}
+
+/// Walk up from the current directory until a project file is found.
+pub fn find_project_file() -> Option<PathBuf> {
+    let mut dir = env::current_dir().ok()?;
+    loop {
+        let candidate = dir.join(PROJECT_FILE_NAME);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+pub fn load_project_file() -> Result<Option<ProjectFile>, ConfigError> {
+    let path = match find_project_file() {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+
+    let file = File::open(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to open project file: {}", e)))?;
+    let project: ProjectFile = serde_json::from_reader(file).map_err(|e| {
+        ConfigError::ProjectError(format!("Invalid project file {}: {}", path.display(), e))
+    })?;
+
+    Ok(Some(project))
+}
+
+/// Use the explicit deployment ID when given, otherwise fall back to the project file.
+pub fn resolve_deployment_id(explicit: Option<&str>) -> Result<String, ConfigError> {
+    if let Some(id) = explicit {
+        return Ok(id.to_string());
+    }
+
+    load_project_file()?
+        .and_then(|p| p.deployment_id)
+        .ok_or_else(|| {
+            ConfigError::ProjectError(format!(
+                "No deployment ID given. Pass -x <deployment_id> or add \"deployment_id\" to {}",
+                PROJECT_FILE_NAME
+            ))
+        })
+}
+
+/// Where a deployed Tenet proxy can be reached, remembered from `tenet deploy`
+/// because the API has no endpoint to look it up later.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TenetEndpoint {
+    pub host: Option<String>,
+    pub proxy_port: Option<u16>,
+}
+
+fn tenet_endpoints_path() -> Result<PathBuf, ConfigError> {
+    Ok(dirs::home_dir()
+        .ok_or_else(|| ConfigError::IoError("Home directory not found".to_string()))?
+        .join(".guepard/tenets.json"))
+}
+
+fn load_tenet_endpoints() -> Result<std::collections::BTreeMap<String, TenetEndpoint>, ConfigError>
+{
+    let path = tenet_endpoints_path()?;
+    if !path.exists() {
+        return Ok(Default::default());
+    }
+    let file = File::open(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to open {}: {}", path.display(), e)))?;
+    serde_json::from_reader(file)
+        .map_err(|e| ConfigError::IoError(format!("Invalid {}: {}", path.display(), e)))
+}
+
+fn write_tenet_endpoints(
+    endpoints: &std::collections::BTreeMap<String, TenetEndpoint>,
+) -> Result<(), ConfigError> {
+    let path = tenet_endpoints_path()?;
+    fs::create_dir_all(path.parent().unwrap())
+        .map_err(|e| ConfigError::IoError(format!("Failed to create .guepard directory: {}", e)))?;
+    let file = File::create(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to create {}: {}", path.display(), e)))?;
+    serde_json::to_writer_pretty(&file, endpoints)
+        .map_err(|e| ConfigError::IoError(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+pub fn save_tenet_endpoint(job_id: &str, endpoint: TenetEndpoint) -> Result<(), ConfigError> {
+    let mut endpoints = load_tenet_endpoints()?;
+    endpoints.insert(job_id.to_string(), endpoint);
+    write_tenet_endpoints(&endpoints)
+}
+
+pub fn load_tenet_endpoint(job_id: &str) -> Result<Option<TenetEndpoint>, ConfigError> {
+    Ok(load_tenet_endpoints()?.remove(job_id))
+}
+
+pub fn remove_tenet_endpoint(job_id: &str) -> Result<(), ConfigError> {
+    let mut endpoints = load_tenet_endpoints()?;
+    if endpoints.remove(job_id).is_some() {
+        write_tenet_endpoints(&endpoints)?;
+    }
+    Ok(())
+}
+
+fn tenet_job_ids_path() -> Result<PathBuf, ConfigError> {
+    Ok(dirs::home_dir()
+        .ok_or_else(|| ConfigError::IoError("Home directory not found".to_string()))?
+        .join(".guepard/tenet-jobs.json"))
+}
+
+/// Job IDs the server assigned to Tenets created by `guepard apply`, keyed by
+/// the manifest's "<deployment>/<job_id>", so the next plan finds them again.
+pub fn load_tenet_job_ids() -> Result<std::collections::BTreeMap<String, String>, ConfigError> {
+    let path = tenet_job_ids_path()?;
+    if !path.exists() {
+        return Ok(Default::default());
+    }
+    let file = File::open(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to open {}: {}", path.display(), e)))?;
+    serde_json::from_reader(file)
+        .map_err(|e| ConfigError::IoError(format!("Invalid {}: {}", path.display(), e)))
+}
+
+pub fn save_tenet_job_id(manifest_key: &str, job_id: &str) -> Result<(), ConfigError> {
+    let mut job_ids = load_tenet_job_ids()?;
+    job_ids.insert(manifest_key.to_string(), job_id.to_string());
+    let path = tenet_job_ids_path()?;
+    fs::create_dir_all(path.parent().unwrap())
+        .map_err(|e| ConfigError::IoError(format!("Failed to create .guepard directory: {}", e)))?;
+    let file = File::create(&path)
+        .map_err(|e| ConfigError::IoError(format!("Failed to create {}: {}", path.display(), e)))?;
+    serde_json::to_writer_pretty(&file, &job_ids)
+        .map_err(|e| ConfigError::IoError(format!("Failed to write {}: {}", path.display(), e)))
+}
@@ -7,12 +7,18 @@ pub enum ComputeError {
     #[error("Failed to parse response: {0}")]
     ParseError(String),
 
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     #[error("500 Internal Server Error: {0}")]
     InternalServerError(String),
 
     #[error("Compute not healthy: {0}")]
     NotHealthy(String),
 
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
     #[error("Unexpected error: {0}")]
     Unexpected(String),
 
@@ -13,4 +13,7 @@ pub enum ConfigError {
 
     #[error("Keyring error: {0}")]
     KeyringError(String),
+
+    #[error("Project error: {0}")]
+    ProjectError(String),
 }
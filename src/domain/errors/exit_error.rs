@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// The command ran, but the process has to exit with a specific code, such as
+/// the status of a client it started or the state of a compute. The outcome
+/// was already shown, so the message is only a one-line summary.
+#[derive(Error, Debug)]
+pub enum ExitError {
+    #[error("{program} exited with status {code}")]
+    Child { program: String, code: i32 },
+
+    #[error("Compute is {state}")]
+    ComputeState { state: String, code: i32 },
+}
+
+impl ExitError {
+    pub fn code(&self) -> i32 {
+        match self {
+            ExitError::Child { code, .. } | ExitError::ComputeState { code, .. } => *code,
+        }
+    }
+}
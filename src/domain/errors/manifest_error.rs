@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    #[error("{0}")]
+    IoError(String),
+
+    #[error("Invalid manifest: {0}")]
+    ParseError(String),
+
+    #[error("Invalid manifest: {0}")]
+    Invalid(String),
+
+    #[error("Plan has conflicts that apply cannot resolve: {0}")]
+    Conflict(String),
+
+    #[error("Apply failed: {0}")]
+    ApplyFailed(String),
+}
@@ -3,7 +3,11 @@ pub mod branch_error;
 pub mod compute_error;
 pub mod config_error;
 pub mod deploy_error;
+pub mod exit_error;
 pub mod login_error;
 pub mod logout_error;
+pub mod manifest_error;
+pub mod quota_error;
 pub mod tenet_error;
 pub mod usage_error;
+pub mod wait_error;
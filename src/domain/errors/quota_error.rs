@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum QuotaError {
+    #[error("Quota reached: {used}/{quota} {resource} used. Free some up or upgrade your plan (see 'guepard usage'), or pass --skip-quota-check to try anyway.")]
+    Exceeded {
+        resource: &'static str,
+        used: i32,
+        quota: i32,
+    },
+}
@@ -8,6 +8,9 @@ pub enum TenetError {
     #[error("Failed to parse response: {0}")]
     ParseError(String),
 
+    #[error("404 Not Found: {0}")]
+    NotFound(String),
+
     #[error("Unexpected error: {0}")]
     Unexpected(String),
 
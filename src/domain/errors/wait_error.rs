@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum WaitError {
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Gave up waiting: {0}")]
+    Failed(String),
+}
@@ -33,6 +33,19 @@ For detailed documentation, visit: https://docs.guepard.run",
 pub struct CLI {
     #[clap(subcommand)]
     pub sub_commands: SubCommand,
+
+    /// Show passwords, connection-string credentials and masking salts
+    ///
+    /// Secrets are redacted in table and JSON output by default so they don't
+    /// end up in CI logs or terminal recordings.
+    #[clap(long, global = true)]
+    pub show_secrets: bool,
+    /// Don't check usage against quotas before creating deployments, snapshots or clones
+    ///
+    /// The server still enforces quotas; without the pre-flight check the request
+    /// is sent and fails there instead.
+    #[clap(long, global = true)]
+    pub skip_quota_check: bool,
 }
 
 /// Shared output format option for all commands
@@ -72,9 +85,15 @@ pub enum SubCommand {
     ///   # Update deployment repository name
     ///   guepard deploy -x <deployment_id> -n new-name
     ///
+    ///   # Change the performance profile of an existing deployment
+    ///   guepard deploy -x <deployment_id> --performance-profile gp.g1.medium
+    ///
     ///   # Delete deployment (use with caution)
     ///   guepard deploy -x <deployment_id> --purge
-    Deploy(DeployArgs),
+    ///
+    ///   # Purge every CI deployment older than 3 days
+    ///   guepard deploy purge --filter 'name~^ci-' --older-than 3d
+    Deploy(Box<DeployArgs>),
 
     /// 📸 Create snapshots of your database state (like git commit)
     ///
@@ -144,6 +163,9 @@ pub enum SubCommand {
     ///
     ///   # Restore to a specific snapshot
     ///   guepard checkout -x <deployment_id> -s <snapshot_id>
+    ///
+    ///   # Restore the state a branch had at a point in time
+    ///   guepard checkout -x <deployment_id> --at "2025-06-01 14:00" --branch main
     Checkout(CheckoutArgs),
 
     /// 💻 Manage compute instances (start, stop, status, logs)
@@ -261,6 +283,156 @@ pub enum SubCommand {
     ///   # Create a clone from a snapshot
     ///   guepard clone -x <deployment_id> -s <snapshot_id>
     Clone(CloneArgs),
+
+    /// 📍 Show where a deployment stands right now (like git status)
+    ///
+    /// Summarize the attached branch, the snapshot it sits on, time since the last
+    /// snapshot, compute health, the connection endpoint and pending snapshots.
+    /// Without -x, the deployment ID is read from the nearest .guepard.json.
+    ///
+    /// Examples:
+    ///   # Status of a deployment
+    ///   guepard status -x <deployment_id>
+    ///
+    ///   # Machine-readable status for prompts and CI gates
+    ///   guepard status --json
+    Status(StatusArgs),
+
+    /// 🗺️ Preview the changes needed to match guepard.yaml
+    ///
+    /// Compare the deployments, branches and Tenet proxies declared in the manifest
+    /// with what exists today. Nothing is changed.
+    ///
+    /// Examples:
+    ///   # Show the plan for ./guepard.yaml
+    ///   guepard plan
+    ///
+    ///   # JSON plan for review in a pull request
+    ///   guepard plan -f envs/staging.yaml --json
+    Plan(PlanArgs),
+
+    /// 🏗️ Create or update resources so they match guepard.yaml
+    ///
+    /// Runs the same comparison as 'guepard plan' and then creates what is missing.
+    /// Running apply again on an unchanged manifest does nothing.
+    ///
+    /// Examples:
+    ///   # Apply ./guepard.yaml after confirmation
+    ///   guepard apply
+    ///
+    ///   # Apply without prompting (CI)
+    ///   guepard apply -f envs/staging.yaml --yes --json
+    Apply(ApplyArgs),
+
+    /// 🔌 Print a connection string for your driver or framework
+    ///
+    /// Builds the connection string for a deployment, branch or clone in the
+    /// format your stack expects, for PostgreSQL, MySQL and MongoDB.
+    ///
+    /// Examples:
+    ///   # Plain URI for the deployment
+    ///   guepard connection -x <deployment_id>
+    ///
+    ///   # JDBC URL with TLS required
+    ///   guepard connection -x <deployment_id> --format jdbc --sslmode require
+    ///
+    ///   # Environment variables for a clone
+    ///   guepard connection -x <deployment_id> --clone <clone_id> --format env > .env
+    Connection(ConnectionArgs),
+
+    /// 🌱 Write connection settings to a .env file or export them to the shell
+    ///
+    /// Updates DATABASE_URL and the host/port/database/user/password variables
+    /// in place, keeping every other line and comment in the file.
+    ///
+    /// Examples:
+    ///   # Update ./.env for the deployment
+    ///   guepard env -x <deployment_id> --write .env
+    ///
+    ///   # Use a clone and custom variable names
+    ///   guepard env -x <deployment_id> --clone <clone_id> --write .env --prefix DATABASE_
+    ///
+    ///   # Export into the current shell
+    ///   eval "$(guepard env -x <deployment_id> --shell)"
+    Env(EnvArgs),
+
+    /// 💻 Open psql, mysql or mongosh against a deployment, branch or clone
+    ///
+    /// Resolves the credentials and starts the native client for the database
+    /// provider. The password is passed through the environment (PGPASSWORD,
+    /// MYSQL_PWD), never on the command line. Arguments after -- go to the client.
+    ///
+    /// Examples:
+    ///   # Interactive session
+    ///   guepard connect -x <deployment_id>
+    ///
+    ///   # Run one query against a clone
+    ///   guepard connect -x <deployment_id> --clone <clone_id> -- -c "select count(*) from users"
+    ///
+    ///   # Through a Tenet masking proxy
+    ///   guepard connect -x <deployment_id> --via-tenet <job_id>
+    Connect(ConnectArgs),
+
+    /// 🚚 Run a migration tool between two snapshots, restoring on failure
+    ///
+    /// Takes a snapshot of the checked-out branch, runs the command after -- with
+    /// the connection variables (DATABASE_URL, DB_*, PG*) in its environment, then
+    /// takes a second snapshot recording the command and exit status. When the
+    /// command fails, the branch is restored to the first snapshot.
+    ///
+    /// Examples:
+    ///   # sqlx reads DATABASE_URL
+    ///   guepard migrate -x <deployment_id> -- sqlx migrate run
+    ///
+    ///   # Flyway on a specific branch, keeping a failed state for inspection
+    ///   guepard migrate -x <deployment_id> -b <branch_id> --no-restore -- flyway migrate
+    Migrate(MigrateArgs),
+
+    /// ⚡ Run SQL statements or a script against a deployment or clone
+    ///
+    /// Connects with the deployment credentials and runs everything in one
+    /// transaction, rolling back on the first error. Query results are printed
+    /// as tables (or JSON with --json). PostgreSQL and MySQL only.
+    ///
+    /// Examples:
+    ///   # Seed a clone
+    ///   guepard exec -x <deployment_id> --clone <clone_id> -f seed.sql
+    ///
+    ///   # Run a query
+    ///   guepard exec -x <deployment_id> -c "select count(*) from users"
+    ///
+    ///   # Seed, then snapshot the result
+    ///   guepard exec -x <deployment_id> -f seed.sql --commit -m "Seed demo data"
+    Exec(ExecArgs),
+
+    /// 🩺 Diagnose configuration, login and connectivity problems
+    ///
+    /// Checks the config file and ~/.guepard permissions, token storage and
+    /// expiry, API reachability, latency and clock skew, and, for a deployment,
+    /// DNS resolution and TCP reachability of its endpoint. Each check reports
+    /// pass, warn or fail with a suggested fix; the command fails if any check does.
+    ///
+    /// Examples:
+    ///   guepard doctor
+    ///   guepard doctor -x <deployment_id> --json
+    Doctor(DoctorArgs),
+
+    /// ⏳ Wait until a deployment, compute, snapshot or clone is ready
+    ///
+    /// Polls the resource until it reaches the state given with --for, then
+    /// optionally checks that its endpoint accepts TCP connections or SQL
+    /// queries. Fails when the timeout elapses or the resource fails.
+    ///
+    /// Examples:
+    ///   # Wait for the compute to run and accept queries
+    ///   guepard wait compute <deployment_id> --probe sql
+    ///
+    ///   # Wait for a clone, polling every 10 seconds for up to 15 minutes
+    ///   guepard wait clone <clone_id> --timeout 15m --interval 10s
+    ///
+    ///   # Wait for a snapshot to be written
+    ///   guepard wait snapshot <snapshot_id> -x <deployment_id>
+    Wait(WaitArgs),
 }
 
 // Git-like command arguments
@@ -269,12 +441,16 @@ pub struct ListArgs {
     #[clap(flatten)]
     pub output: OutputArgs,
 
-    /// Resource type to list: deployments, branches, commits, or clones
+    /// Resource type to list: deployments, f2, branches, commits, clones, performance, or images
     ///
     /// - deployments: List all database deployments (default)
+    /// - f2: List F2 deployments
     /// - branches: List branches for a deployment (requires --deployment-id)
     /// - commits: List snapshots/commits for a deployment (requires --deployment-id)
-    /// - clones: List shadow/clone deployments for a deployment (requires --deployment-id)
+    /// - clones: List shadow/clone deployments for a deployment (requires --deployment-id,
+    ///   or --all for every clone in the account)
+    /// - performance: List performance profiles
+    /// - images: List database image providers (valid --database-provider/--database-version pairs)
     #[clap(value_parser, default_value = "deployments")]
     pub resource: String,
 
@@ -301,10 +477,11 @@ pub struct ListArgs {
     #[clap(short = 'g', long)]
     pub graph: bool,
 
-    /// Show all commits including AUTO SNAPs (for commits only)
+    /// Show all commits including AUTO SNAPs, or all clones across deployments
     ///
-    /// By default, AUTO SNAPs (automatic snapshots) are hidden. Use this flag
-    /// to include them in the output.
+    /// For commits, AUTO SNAPs (automatic snapshots) are hidden by default; this flag
+    /// includes them. For clones, lists every clone in the account with its parent
+    /// deployment and source snapshot instead of requiring --deployment-id.
     #[clap(short = 'a', long)]
     pub all: bool,
 
@@ -321,6 +498,15 @@ pub struct DeployArgs {
     #[clap(flatten)]
     pub output: OutputArgs,
 
+    /// Bulk action on the deployments selected by --all, --filter, --status or --older-than
+    ///
+    ///   - purge: stop computes and permanently delete each selected deployment
+    #[clap(value_parser = ["purge"])]
+    pub action: Option<String>,
+
+    #[clap(flatten)]
+    pub bulk: BulkArgs,
+
     /// Database provider type
     ///
     /// Supported providers: PostgreSQL, MySQL, MongoDB
@@ -409,23 +595,65 @@ pub struct DeployArgs {
     ///   - gp.g1.medium: 4 vCPU, 8GB RAM (medium production)
     ///   - gp.g1.large: 8 vCPU, 16GB RAM (large production, high-traffic)
     ///
-    /// If not specified, defaults to gp.g1.xsmall.
-    #[clap(short = 'f', long)]
+    /// If not specified, defaults to gp.g1.xsmall. With -x, applies the profile to the
+    /// existing deployment and waits for its compute to become healthy again.
+    #[clap(short = 'f', long, conflicts_with = "purge")]
     pub performance_profile: Option<String>,
 
+    /// Store the database password in the OS keyring instead of printing it
+    ///
+    /// Builds without the `keyring` feature write it to a plaintext file,
+    /// ~/.guepard/secrets/<deployment_id> (mode 0600), instead.
+    #[clap(long)]
+    pub secret_to_keyring: bool,
+
+    /// After creating, wait until the deployment's compute is running (up to 10 minutes)
+    #[clap(long)]
+    pub wait: bool,
+
     /// Node ID for the deployment
     ///
     /// Optional node identifier for the deployment. Used for specific node targeting.
     #[clap(short = 's', long)]
     pub node_id: Option<String>,
 
+    /// Image provider to deploy from, by ID or image name
+    ///
+    /// Sets the database provider and version from the image; see
+    /// 'guepard list images'. Can replace -p and -v when creating a deployment.
+    #[clap(long)]
+    pub image_provider: Option<String>,
+
     /// Interactive mode - guided setup wizard
     ///
     /// Launches an interactive wizard that guides you through deployment creation
-    /// step by step. Recommended for first-time users or complex deployments.
+    /// step by step, with choices loaded from the API and a final review. The
+    /// answers can also be saved as a guepard.yaml manifest or JSON request file.
     #[clap(short = 'I', long)]
     pub interactive: bool,
 
+    /// Create the deployment from a JSON or YAML request file
+    ///
+    /// Accepts a single deployment (as saved by the wizard or --export-template) or
+    /// a guepard.yaml with exactly one deployment. Flags given on the command line
+    /// override the file; the password comes from -w or the variable named by
+    /// password_env.
+    #[clap(long, value_name = "PATH", conflicts_with_all = ["deployment_id", "interactive"])]
+    pub from_file: Option<String>,
+
+    /// Export an existing deployment as a reusable request file. Use with -x.
+    ///
+    /// Writes YAML, or JSON when FILE ends in .json; prints to stdout when FILE is
+    /// omitted. Secrets are never included.
+    #[clap(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        requires = "deployment_id",
+        conflicts_with_all = ["purge", "repository_name", "performance_profile"]
+    )]
+    pub export_template: Option<Option<String>>,
+
     /// Show git-style graph visualization of commits
     ///
     /// Displays commit history in a visual graph format similar to 'git log --graph'.
@@ -530,6 +758,12 @@ pub struct BranchArgs {
     /// the branch will be created from the snapshot specified by --snapshot-id.
     #[clap(short = 'b', long)]
     pub source_branch_id: Option<String>,
+    /// Skip the safety snapshot taken before discarding changes
+    ///
+    /// By default, --discard-changes true first snapshots the current branch with the
+    /// comment "pre-checkout safety snapshot" so the discarded work can be restored.
+    #[clap(long)]
+    pub no_safety_snapshot: bool,
 }
 
 #[derive(Args, Debug)]
@@ -547,10 +781,15 @@ pub struct LogArgs {
     /// Number of log lines to display
     ///
     /// Controls how many lines of log history to show. Default: 50.
+    /// Shows the last lines unless --head is given; --tail is an alias.
     /// Use a larger number to see more history, or combine with --follow for real-time streaming.
-    #[clap(short = 'n', long, default_value = "50")]
+    #[clap(short = 'n', long, visible_alias = "tail", default_value = "50")]
     pub lines: usize,
 
+    /// Show the first --lines lines instead of the last
+    #[clap(long, conflicts_with = "follow")]
+    pub head: bool,
+
     /// Follow mode - stream logs in real-time
     ///
     /// Similar to 'tail -f', continuously displays new log entries as they are generated.
@@ -558,6 +797,12 @@ pub struct LogArgs {
     #[clap(short = 'f', long)]
     pub follow: bool,
 
+    /// Seconds between polls in follow mode
+    ///
+    /// Polls back off exponentially (up to a minute) while the log endpoint fails.
+    #[clap(long, default_value_t = 2)]
+    pub interval: u64,
+
     /// Show only stdout logs
     ///
     /// Filter to display only standard output logs, excluding error logs.
@@ -582,26 +827,86 @@ pub struct LogArgs {
     /// Filter logs from this date/time onwards
     ///
     /// Show only logs created on or after this date/time.
-    /// Format: YYYY-MM-DD or YYYY-MM-DD HH:MM:SS
+    /// Format: YYYY-MM-DD, YYYY-MM-DD HH:MM:SS or a relative time
     ///
     /// Examples:
     ///   --since "2025-01-08"
     ///   --since "2025-01-08 14:30:00"
+    ///   --since 15m
+    ///   --since "2 hours ago"
     #[clap(long)]
     pub since: Option<String>,
 
     /// Filter logs until this date/time
     ///
     /// Show only logs created on or before this date/time.
-    /// Format: YYYY-MM-DD or YYYY-MM-DD HH:MM:SS
+    /// Format: YYYY-MM-DD, YYYY-MM-DD HH:MM:SS or a relative time
     ///
     /// Examples:
     ///   --until "2025-01-09"
     ///   --until "2025-01-09 18:00:00"
+    ///   --until 5m
     ///
     /// Can be combined with --since to create a date range.
     #[clap(long)]
     pub until: Option<String>,
+
+    /// Show only lines at this level, or at this level and above with a trailing '+'
+    ///
+    /// Levels: trace, debug, info, warn, error
+    ///
+    /// Examples:
+    ///   --level error
+    ///   --level warn+
+    #[clap(long)]
+    pub level: Option<String>,
+
+    /// Show only lines matching this regular expression
+    ///
+    /// Examples:
+    ///   --grep deadlock
+    ///   --grep "(?i)connection (reset|refused)"
+    #[clap(long)]
+    pub grep: Option<String>,
+
+    /// Show only lines NOT matching --grep
+    #[clap(long, requires = "grep")]
+    pub invert_grep: bool,
+
+    /// Lines of context to show around each --grep/--level match, like grep -C
+    #[clap(short = 'C', long, default_value_t = 0)]
+    pub context: usize,
+
+    /// Write the parsed logs to this file instead of the terminal
+    ///
+    /// Each line keeps its timestamp, source and level. Filters and --lines apply.
+    ///
+    /// Examples:
+    ///   --output-file incident.ndjson
+    ///   --output-file incident.csv --since 2h --level warn+
+    #[clap(long)]
+    pub output_file: Option<std::path::PathBuf>,
+
+    /// File format for --output-file
+    ///
+    /// Defaults to csv or text for .csv, .txt and .log files, otherwise ndjson.
+    #[clap(long, requires = "output_file", value_parser = ["ndjson", "csv", "text"])]
+    pub format: Option<String>,
+
+    /// Start a new output file at this size or age (follow mode)
+    ///
+    /// A size (50MB, 512KB) or a duration (30m, 1h). The current file becomes
+    /// <file>.1, older ones shift up to <file>.<keep>.
+    #[clap(long, requires_all = ["output_file", "follow"])]
+    pub rotate: Option<String>,
+
+    /// Number of rotated files to keep
+    #[clap(long, default_value_t = 5, requires = "rotate")]
+    pub keep: usize,
+
+    /// Gzip rotated files (<file>.1.gz)
+    #[clap(long, requires = "rotate")]
+    pub gzip: bool,
 }
 
 #[derive(Args, Debug)]
@@ -638,6 +943,21 @@ pub struct CheckoutArgs {
     #[clap(short = 's', long)]
     pub snapshot_id: Option<String>,
 
+    /// Restore the newest snapshot taken at or before this time
+    ///
+    /// Accepts the same formats as `guepard log --since`, including relative times.
+    /// Searches the attached branch unless --branch is given.
+    ///
+    /// Examples:
+    ///   --at "2025-06-01 14:00"
+    ///   --at "2 hours ago"
+    #[clap(long, conflicts_with_all = ["branch_id", "snapshot_id"])]
+    pub at: Option<String>,
+
+    /// Branch name or ID to search when using --at
+    #[clap(long, requires = "at")]
+    pub branch: Option<String>,
+
     /// Discard uncommitted changes before checkout
     ///
     /// Set to "true" to discard any uncommitted changes before switching branches
@@ -658,6 +978,12 @@ pub struct CheckoutArgs {
     /// This is used in advanced workflows where checkout creates a new branch.
     #[clap(short = 'b', long)]
     pub source_branch_id: Option<String>,
+    /// Skip the safety snapshot taken before discarding changes
+    ///
+    /// By default, --discard-changes true first snapshots the current branch with the
+    /// comment "pre-checkout safety snapshot" so the discarded work can be restored.
+    #[clap(long)]
+    pub no_safety_snapshot: bool,
 }
 
 #[derive(Args, Debug)]
@@ -667,10 +993,11 @@ pub struct ComputeArgs {
 
     /// Deployment ID to manage compute for
     ///
-    /// Required. Find deployment IDs with:
-    ///   guepard list deployments
-    #[clap(short = 'x', long, required = true)]
-    pub deployment_id: String,
+    /// Find deployment IDs with 'guepard list deployments'. Optional when a
+    /// .guepard.json project file exists, or when selecting several deployments
+    /// with --all/--filter for start, stop and restart.
+    #[clap(short = 'x', long, conflicts_with_all = ["all", "filters", "status", "older_than"])]
+    pub deployment_id: Option<String>,
 
     /// Action to perform on compute instance
     ///
@@ -678,16 +1005,52 @@ pub struct ComputeArgs {
     ///   - status: Check current compute status (running, stopped, etc.)
     ///   - start: Start the compute instance
     ///   - stop: Stop the compute instance
-    ///   - restart: Restart the compute instance
+    ///   - restart: Stop, wait until stopped, start and wait until running
     ///   - logs: View compute-specific logs
     ///   - list: Show detailed compute information (default if not specified)
+    ///   - resize: Apply another performance profile (requires --performance-profile)
     ///
     /// Examples:
     ///   guepard compute status -x <deployment_id>
     ///   guepard compute start -x <deployment_id>
     ///   guepard compute stop -x <deployment_id>
+    ///   guepard compute resize -x <deployment_id> -f gp.g1.medium
+    ///   guepard compute stop --all --status running
     #[clap(value_parser)]
     pub action: Option<String>,
+
+    /// Performance profile label to apply with 'resize'
+    ///
+    /// Must be available for the deployment's provider and version.
+    /// List profiles with: guepard list performance
+    #[clap(short = 'f', long)]
+    pub performance_profile: Option<String>,
+
+    /// Seconds to wait for the compute to become healthy after a resize, a
+    /// restart or start --wait
+    #[clap(long, default_value_t = 300)]
+    pub wait_timeout: u64,
+
+    /// After 'start', wait until the compute reports running ('restart' always waits)
+    #[clap(long)]
+    pub wait: bool,
+
+    /// Seconds to wait for the compute to stop during a restart
+    ///
+    /// If it does not stop in time, the restart starts it again and fails.
+    #[clap(long, default_value_t = 120)]
+    pub stop_timeout: u64,
+
+    /// With 'restart' on an F2 deployment, also restart all of its shadows
+    #[clap(long, conflicts_with_all = ["all", "filters", "status", "older_than"])]
+    pub all_shadows: bool,
+
+    #[clap(flatten)]
+    pub bulk: BulkArgs,
+
+    /// Skip the confirmation prompt for bulk start/stop/restart
+    #[clap(short = 'y', long)]
+    pub yes: bool,
 }
 
 #[derive(Args, Debug)]
@@ -883,6 +1246,14 @@ pub struct ConfigArgs {
     #[clap(long)]
     pub app_url: Option<String>,
 
+    /// Warn when creating a deployment, snapshot or clone brings usage to this
+    /// percentage of the quota (default: 80)
+    ///
+    /// Example:
+    ///   guepard config --quota-warn-threshold 90
+    #[clap(long, value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub quota_warn_threshold: Option<u8>,
+
     /// Show all current configuration settings
     ///
     /// Display all configuration values including API endpoint and other settings.
@@ -933,6 +1304,17 @@ pub struct CloneArgs {
     /// If not specified, defaults to gp.g1.xsmall.
     #[clap(short = 'f', long)]
     pub performance_profile: Option<String>,
+
+    /// Store the database password in the OS keyring instead of printing it
+    ///
+    /// Builds without the `keyring` feature write it to a plaintext file,
+    /// ~/.guepard/secrets/<deployment_id> (mode 0600), instead.
+    #[clap(long)]
+    pub secret_to_keyring: bool,
+
+    /// Wait until the clone's compute is running (up to 10 minutes)
+    #[clap(long)]
+    pub wait: bool,
 }
 
 #[derive(Args, Debug)]
@@ -946,3 +1328,304 @@ pub struct LogoutArgs {
     #[clap(flatten)]
     pub output: OutputArgs,
 }
+
+#[derive(Args, Debug)]
+pub struct StatusArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment ID to summarize
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct PlanArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Path to the manifest file
+    #[clap(short = 'f', long, default_value = "guepard.yaml")]
+    pub file: std::path::PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ApplyArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Path to the manifest file
+    #[clap(short = 'f', long, default_value = "guepard.yaml")]
+    pub file: std::path::PathBuf,
+
+    /// Apply without asking for confirmation (required with --json)
+    #[clap(short = 'y', long)]
+    pub yes: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ConnectionArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment ID
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// Branch ID or name (warns if the branch is not checked out)
+    #[clap(short = 'c', long, conflicts_with = "clone")]
+    pub branch: Option<String>,
+
+    /// Clone ID to connect to instead of the deployment
+    #[clap(long)]
+    pub clone: Option<String>,
+
+    /// Output format
+    ///
+    /// uri, jdbc, dotnet, prisma, sqlalchemy, django, mongodb-srv, psql-args, env
+    #[clap(short = 'f', long, default_value = "uri")]
+    pub format: String,
+
+    /// TLS mode using libpq names: disable, allow, prefer, require, verify-ca, verify-full
+    ///
+    /// Translated to the equivalent MySQL ssl-mode or MongoDB tls option.
+    #[clap(long, value_parser = ["disable", "allow", "prefer", "require", "verify-ca", "verify-full"])]
+    pub sslmode: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct EnvArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment ID
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// Clone ID to use instead of the deployment
+    #[clap(long)]
+    pub clone: Option<String>,
+
+    /// Dotenv file to update (created if missing)
+    #[clap(short = 'w', long, conflicts_with = "shell")]
+    pub write: Option<String>,
+
+    /// Variable name prefix, e.g. DATABASE_ for DATABASE_HOST, DATABASE_PORT, ...
+    ///
+    /// Without a prefix, PostgreSQL uses DATABASE_URL plus libpq's PGHOST, PGPORT,
+    /// PGDATABASE, PGUSER and PGPASSWORD; other engines use DATABASE_URL plus
+    /// DB_HOST, DB_PORT, ... With a prefix, the URI is written to <PREFIX>URL.
+    #[clap(long)]
+    pub prefix: Option<String>,
+
+    /// Print `export` statements for eval
+    #[clap(long)]
+    pub shell: bool,
+
+    /// TLS mode using libpq names: disable, allow, prefer, require, verify-ca, verify-full
+    #[clap(long, value_parser = ["disable", "allow", "prefer", "require", "verify-ca", "verify-full"])]
+    pub sslmode: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct ConnectArgs {
+    /// Deployment ID
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// Branch ID or name (warns if the branch is not checked out)
+    #[clap(short = 'c', long, conflicts_with = "clone")]
+    pub branch: Option<String>,
+
+    /// Clone ID to connect to instead of the deployment
+    #[clap(long)]
+    pub clone: Option<String>,
+
+    /// Connect through this Tenet proxy job instead of directly
+    ///
+    /// Uses the host and proxy port remembered from 'guepard tenet deploy'; pass
+    /// --tenet-host/--tenet-port if the job was deployed elsewhere.
+    #[clap(long)]
+    pub via_tenet: Option<String>,
+
+    /// Address clients use to reach the Tenet proxy
+    #[clap(long, requires = "via_tenet")]
+    pub tenet_host: Option<String>,
+
+    /// Tenet proxy port
+    #[clap(long, requires = "via_tenet")]
+    pub tenet_port: Option<u16>,
+
+    /// TLS mode using libpq names: disable, allow, prefer, require, verify-ca, verify-full
+    ///
+    /// Defaults to disable when connecting through Tenet.
+    #[clap(long, value_parser = ["disable", "allow", "prefer", "require", "verify-ca", "verify-full"])]
+    pub sslmode: Option<String>,
+
+    /// Extra arguments passed to the client (after --)
+    #[clap(last = true)]
+    pub args: Vec<String>,
+}
+
+/// Deployment selection shared by bulk operations (`deploy purge`, `compute stop`, ...)
+#[derive(Args, Debug)]
+pub struct BulkArgs {
+    /// Select all deployments in the account (narrow with the options below)
+    #[clap(long)]
+    pub all: bool,
+
+    /// Match a deployment field: name=ci-1, status!=INIT, name~^ci- (regex), region!~^eu
+    ///
+    /// Fields: id, name, repository_name, status, deployment_type, database_provider,
+    /// database_version, fqdn, port, region, datacenter, created_date, created_by.
+    /// = and != ignore case. Repeat to require several conditions.
+    #[clap(long = "filter", value_name = "EXPR")]
+    pub filters: Vec<String>,
+
+    /// Only deployments with this status (case-insensitive)
+    #[clap(long)]
+    pub status: Option<String>,
+
+    /// Only deployments created before this age or date (30m, 12h, 3d, 2025-10-01)
+    #[clap(long, value_name = "AGE")]
+    pub older_than: Option<String>,
+
+    /// Maximum number of deployments processed at the same time
+    #[clap(long, default_value_t = 4, value_parser = clap::value_parser!(usize))]
+    pub concurrency: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct MigrateArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment ID
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// Branch ID or name to migrate (must be checked out; defaults to the checked-out branch)
+    #[clap(short = 'b', long)]
+    pub branch: Option<String>,
+
+    /// Keep the failed state instead of restoring the pre-migration snapshot
+    #[clap(long)]
+    pub no_restore: bool,
+
+    /// Migration command and its arguments (after --)
+    #[clap(last = true, required = true)]
+    pub command: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+#[command(group(
+    ArgGroup::new("sql")
+        .args(["file", "command"])
+        .required(true),
+))]
+pub struct ExecArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment ID
+    ///
+    /// Optional when a .guepard.json project file with a "deployment_id" exists
+    /// in the current directory or one of its parents.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// Clone ID to run against instead of the deployment
+    #[clap(long)]
+    pub clone: Option<String>,
+
+    /// SQL script to run
+    #[clap(short = 'f', long)]
+    pub file: Option<String>,
+
+    /// SQL statements to run
+    #[clap(short = 'c', long)]
+    pub command: Option<String>,
+
+    /// Create a snapshot of the checked-out branch after a successful run
+    #[clap(long, conflicts_with = "clone")]
+    pub commit: bool,
+
+    /// Snapshot comment for --commit (default: "After exec: <file>")
+    #[clap(short = 'm', long, requires = "commit")]
+    pub message: Option<String>,
+
+    /// TLS mode using libpq names: disable, allow, prefer, require, verify-ca, verify-full
+    ///
+    /// Defaults to prefer (TLS when the server offers it, without certificate checks).
+    #[clap(long, value_parser = ["disable", "allow", "prefer", "require", "verify-ca", "verify-full"])]
+    pub sslmode: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct DoctorArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Deployment whose endpoint to check (DNS and TCP)
+    ///
+    /// Defaults to the deployment in .guepard.json, if any.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    #[clap(flatten)]
+    pub output: OutputArgs,
+
+    /// Kind of resource to wait for
+    #[clap(value_parser = ["deployment", "compute", "snapshot", "clone"])]
+    pub resource: String,
+
+    /// ID of the resource
+    ///
+    /// For deployment and compute, defaults to the deployment in .guepard.json.
+    pub id: Option<String>,
+
+    /// Deployment the snapshot belongs to
+    ///
+    /// Defaults to the deployment in .guepard.json.
+    #[clap(short = 'x', long)]
+    pub deployment_id: Option<String>,
+
+    /// State to wait for
+    ///
+    /// Compute states: pending, starting, running (default), stopping, stopped,
+    /// failed. For other resources, a status such as CREATED, or "ready"
+    /// (default) for any finished status.
+    #[clap(long = "for", value_name = "STATE")]
+    pub target: Option<String>,
+
+    /// Give up after this long (e.g. 90s, 10m, 1h)
+    #[clap(long, default_value = "10m")]
+    pub timeout: String,
+
+    /// Time between polls
+    #[clap(long, default_value = "5s")]
+    pub interval: String,
+
+    /// Once the state is reached, also require the endpoint to accept a TCP
+    /// connection (tcp) or a `SELECT 1` (sql)
+    #[clap(long, value_parser = ["tcp", "sql"])]
+    pub probe: Option<String>,
+}